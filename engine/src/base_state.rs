@@ -11,11 +11,11 @@ use ecs::prelude::LegionState;
 use kernel::graphics::scene::Lights;
 use kernel::*;
 use kernel::{
-    abstract_runtime::{ClockworkState, Delegate, Substate},
+    abstract_runtime::{ClockworkState, Delegate, SessionRecording},
     graphics::*,
     prelude::StandardEvent,
 };
-use main_loop::state::{InitWinitState, InputState, MainLoopStatistics};
+use main_loop::state::{InitWinitState, InputChange, InputState, MainLoopStatistics};
 use physics::prelude::PhysicsState;
 
 pub use assets_wrapper::Assets;
@@ -29,31 +29,31 @@ mod assets_wrapper;
 mod legion_rapier_wrapper;
 mod scene_instance;
 
-#[derive(Builder, Delegate)]
-#[delegate(Substate<ColoredMeshStorage<AssetT>>, target = "assets")]
-#[delegate(Substate<TexturedMeshStorage<AssetT>>, target = "assets")]
-#[delegate(Substate<PhongMaterialStorage<AssetT>>, target = "assets")]
-#[delegate(Substate<LegionState>, target = "ecs")]
-#[delegate(Substate<GuiState>, target="ecs")]
-#[delegate(Substate<MainLoopStatistics>, target = "ecs")]
-#[delegate(Substate<InputState>, target = "ecs")]
-#[delegate(Substate<PhysicsState>, target = "ecs")]
+#[derive(Builder, ClockworkState, Delegate)]
 // #[delegate(Scene<LayerKey = u32>, target = "ecs")]
 #[delegate(SceneObjects<SceneInstance<AssetT>>, target = "ecs")]
 #[delegate(PrimaryCamera<SceneCamera>, target = "ecs")]
 // #[delegate(Lights<SceneAmbientLight, SceneDirectionalLight, ScenePointLight, SceneSpotLight>, target = "ecs")]
-#[delegate(Substate<InitWinitState<StandardEvent>>, target = "main_loop_state")]
 #[builder(pattern = "owned", setter(into, prefix = "with"), build_fn(skip))]
 pub struct BaseState<AssetT>
 where
     AssetT: AssetStorageKey,
 {
     #[builder(setter(skip))]
+    #[clockwork(substates(LegionState, GuiState, MainLoopStatistics, InputState, PhysicsState))]
     ecs: ECSWrapper,
 
     #[builder(setter(skip))]
     main_loop_state: InitWinitState<StandardEvent>,
 
+    #[builder(setter(skip))]
+    session: SessionRecording<StandardEvent, InputChange>,
+
+    #[clockwork(substates(
+        ColoredMeshStorage<AssetT>,
+        TexturedMeshStorage<AssetT>,
+        PhongMaterialStorage<AssetT>
+    ))]
     assets: Assets<AssetT>,
 }
 
@@ -61,8 +61,6 @@ impl<T: AssetStorageKey> Scene for BaseState<T> {
     type LayerKey = u32;
 }
 
-impl<AssetT> ClockworkState for BaseState<AssetT> where AssetT: AssetStorageKey {}
-
 impl<AssetT> BaseState<AssetT>
 where
     AssetT: AssetStorageKey,
@@ -82,6 +80,7 @@ where
         Ok(BaseState {
             ecs: ECSWrapper::new(main_loop_state.proxy().clone()),
             main_loop_state,
+            session: Default::default(),
             assets: assets.ok_or("Missing assets")?,
         })
     }
@@ -1,13 +1,9 @@
 use asset_storage::asset_storage::AssetStorageKey;
 use derive_builder::Builder;
-use kernel::abstract_runtime::{ClockworkState, Delegate, Substate};
-use kernel::*;
+use kernel::abstract_runtime::ClockworkState;
 use scene::prelude::{ColoredMeshStorage, PhongMaterialStorage, TexturedMeshStorage};
 
-#[derive(Builder, Delegate)]
-#[delegate(Substate<ColoredMeshStorage<C>>, target="colored_meshes")]
-#[delegate(Substate<TexturedMeshStorage<C>>, target="static_meshes")]
-#[delegate(Substate<PhongMaterialStorage<C>>, target="materials")]
+#[derive(Builder, ClockworkState)]
 #[builder(pattern = "owned", setter(into))]
 pub struct Assets<C>
 where
@@ -26,5 +22,3 @@ where
         Default::default()
     }
 }
-
-impl<C> ClockworkState for Assets<C> where C: AssetStorageKey {}
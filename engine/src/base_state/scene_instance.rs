@@ -6,25 +6,24 @@ use kernel::{
     abstract_runtime::Delegate,
     graphics::{
         scene_object::{Camera, Material, Mesh},
-        scene_object_components::ProjectionMatrix,
+        scene_object_components::{ProjectionMatrix, WorldTransform},
         AmbientLight, DirectionalLight, Light, PointLight, SceneObject, SpotLight,
     },
-    math::Mat4,
+    math::{Quat, Transform},
 };
 use physics::prelude::RigidBody;
 
 struct Body(RigidBody);
-impl SceneObject for Body {
-    fn world_matrix(&self) -> kernel::math::Mat4 {
-        Into::<[[f32; 4]; 4]>::into(self.0.position().to_matrix()).into()
-    }
-
-    fn view_matrix(&self) -> kernel::math::Mat4 {
-        Into::<[[f32; 4]; 4]>::into(self.0.position().inverse().to_matrix()).into()
-    }
-
-    fn normal_matrix(&self) -> kernel::math::Mat4 {
-        Into::<[[f32; 4]; 4]>::into(self.0.position().inverse().to_matrix().transpose()).into()
+impl WorldTransform for Body {
+    fn world_transform(&self) -> Transform {
+        let position = self.0.position();
+        let translation: [f32; 3] = position.translation.vector.into();
+        let [x, y, z, w]: [f32; 4] = position.rotation.coords.into();
+        Transform::new(
+            translation.into(),
+            Quat::new(x, y, z, w),
+            [1.0, 1.0, 1.0].into(),
+        )
     }
 }
 
@@ -0,0 +1,227 @@
+use super::{
+    event::ClockworkEvent,
+    mechanism::Mechanism,
+    state::{ClockworkState, EngineState, Substate},
+};
+use crate::standard_runtime::StandardRuntimeStatistics;
+use std::marker::PhantomData;
+
+/// A mechanism, which is defined by a closure.
+///
+/// Closure mechanisms are usually added with `ClockworkBuilder::on`,
+/// or `ClockworkBuilder::on_event`.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::{abstract_runtime::*, prelude::*};
+/// # struct State(u32);
+/// # impl ClockworkState for State {}
+/// let counter = ClosureMechanism::new(
+///     Some(vec![StandardEvent::Tick]),
+///     |state: &mut EngineState<State>, _: StandardEvent| {
+///         state.start_mutate().get_mut(|s: &mut State| s.0 += 1).finish()
+///     },
+/// );
+/// ```
+pub struct ClosureMechanism<F, K> {
+    /// Event kinds, handled by the closure, or None for every event
+    handled_events: Option<Vec<K>>,
+
+    /// The reaction on the event
+    closure: F,
+}
+
+impl<F, K> ClosureMechanism<F, K> {
+    /// Creates a mechanism, which calls the closure upon the handled events.
+    pub fn new(handled_events: Option<Vec<K>>, closure: F) -> Self {
+        Self {
+            handled_events,
+            closure,
+        }
+    }
+}
+
+impl<S, E, F> Mechanism<S, E> for ClosureMechanism<F, E::Kind>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+    F: FnMut(&mut EngineState<S>, E),
+{
+    fn clink(&mut self, state: &mut EngineState<S>, event: E) {
+        (self.closure)(state, event)
+    }
+
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
+        self.handled_events.clone()
+    }
+}
+
+/// Combinators, which change the events a mechanism sees.
+///
+/// The combinators do not change the routing of the events:
+/// the combined mechanism handles the same event kinds as the original one.
+pub trait MechanismExt<S, E>
+where
+    Self: Mechanism<S, E> + Sized,
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// Only passes the events, which satisfy the predicate, to the mechanism.
+    fn filter<P>(self, predicate: P) -> Filtered<Self, P>
+    where
+        P: FnMut(&E) -> bool,
+    {
+        Filtered {
+            mechanism: self,
+            predicate,
+        }
+    }
+
+    /// Transforms the events before passing them to the mechanism.
+    fn map<F>(self, transform: F) -> Mapped<Self, F>
+    where
+        F: FnMut(E) -> E,
+    {
+        Mapped {
+            mechanism: self,
+            transform,
+        }
+    }
+
+    /// Passes at most one event per period to the mechanism, dropping the events in between.
+    ///
+    /// The period is measured in ticks, counted by the runtime statistics `T`,
+    /// so the throttling does not depend on the clock (e.g. during a replay).
+    fn throttle<T>(self, period: u64) -> Throttled<Self, T>
+    where
+        S: Substate<T>,
+        T: StandardRuntimeStatistics,
+        T::Count: Into<u64>,
+    {
+        Throttled {
+            mechanism: self,
+            period,
+            last_clink: None,
+            phantom_data: Default::default(),
+        }
+    }
+}
+impl<S, E, M> MechanismExt<S, E> for M
+where
+    M: Mechanism<S, E>,
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+}
+
+/// A mechanism, which only sees the events, satisfying the predicate.
+///
+/// See `MechanismExt::filter`.
+pub struct Filtered<M, P> {
+    /// The original mechanism
+    mechanism: M,
+
+    /// Decides, whether the event is passed to the mechanism
+    predicate: P,
+}
+
+impl<S, E, M, P> Mechanism<S, E> for Filtered<M, P>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+    M: Mechanism<S, E>,
+    P: FnMut(&E) -> bool,
+{
+    fn clink(&mut self, state: &mut EngineState<S>, event: E) {
+        if (self.predicate)(&event) {
+            self.mechanism.clink(state, event)
+        }
+    }
+
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
+        self.mechanism.handled_events()
+    }
+
+    fn removal(&mut self, state: &mut EngineState<S>) {
+        self.mechanism.removal(state)
+    }
+}
+
+/// A mechanism, which sees the transformed events.
+///
+/// See `MechanismExt::map`.
+pub struct Mapped<M, F> {
+    /// The original mechanism
+    mechanism: M,
+
+    /// Transforms the event
+    transform: F,
+}
+
+impl<S, E, M, F> Mechanism<S, E> for Mapped<M, F>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+    M: Mechanism<S, E>,
+    F: FnMut(E) -> E,
+{
+    fn clink(&mut self, state: &mut EngineState<S>, event: E) {
+        self.mechanism.clink(state, (self.transform)(event))
+    }
+
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
+        self.mechanism.handled_events()
+    }
+
+    fn removal(&mut self, state: &mut EngineState<S>) {
+        self.mechanism.removal(state)
+    }
+}
+
+/// A mechanism, which sees at most one event per period.
+///
+/// See `MechanismExt::throttle`.
+pub struct Throttled<M, T> {
+    /// The original mechanism
+    mechanism: M,
+
+    /// The minimal amount of ticks between the clinks
+    period: u64,
+
+    /// The total amount of ticks at the last clink
+    last_clink: Option<u64>,
+
+    /// Phantom data for statistics type
+    phantom_data: PhantomData<T>,
+}
+
+impl<S, E, M, T> Mechanism<S, E> for Throttled<M, T>
+where
+    S: Substate<T>,
+    E: ClockworkEvent,
+    M: Mechanism<S, E>,
+    T: StandardRuntimeStatistics,
+    T::Count: Into<u64>,
+{
+    fn clink(&mut self, state: &mut EngineState<S>, event: E) {
+        let now = state
+            .start_access()
+            .get(|statistics: &T| statistics.ticks_total().into())
+            .finish();
+        if let Some(last_clink) = self.last_clink {
+            if now.saturating_sub(last_clink) < self.period {
+                return;
+            }
+        }
+        self.last_clink = Some(now);
+        self.mechanism.clink(state, event)
+    }
+
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
+        self.mechanism.handled_events()
+    }
+
+    fn removal(&mut self, state: &mut EngineState<S>) {
+        self.mechanism.removal(state)
+    }
+}
@@ -0,0 +1,147 @@
+use super::{
+    event::ClockworkEvent,
+    mechanism::Mechanism,
+    ordering::{MechanismLabel, MechanismOrdering},
+    state::{ClockworkState, EngineState},
+    termination::TerminationReason,
+};
+use std::any::Any;
+
+/// A deferred change of the set of mechanisms.
+///
+/// Commands are queued by the mechanisms through the `EngineState`,
+/// and are applied by `Mechanisms` between the events.
+pub(crate) enum MechanismCommand {
+    /// Adds a type-erased `(Box<dyn Mechanism<S, E>>, MechanismOrdering)`
+    Add(Box<dyn Any>),
+
+    /// Removes every mechanism with the label
+    Remove(MechanismLabel),
+
+    /// Enables every mechanism with the label
+    Enable(MechanismLabel),
+
+    /// Disables every mechanism with the label
+    Disable(MechanismLabel),
+}
+
+/// A queue of runtime commands, which is carried by the `EngineState`.
+///
+/// The queue is not aware of the event type,
+/// so mechanisms, which are to be added, are stored type-erased.
+#[derive(Default)]
+pub(crate) struct RuntimeCommands {
+    /// Pending changes of the set of mechanisms
+    pub(crate) mechanism_commands: Vec<MechanismCommand>,
+
+    /// Emitted follow-up events of a type-erased event type
+    pub(crate) events: Vec<Box<dyn Any>>,
+
+    /// The first termination request
+    pub(crate) termination: Option<TerminationReason>,
+}
+
+impl RuntimeCommands {
+    /// Moves the commands of another queue to the end of this one.
+    pub(crate) fn append(&mut self, mut other: RuntimeCommands) {
+        self.mechanism_commands
+            .append(&mut other.mechanism_commands);
+        self.events.append(&mut other.events);
+        self.termination = self.termination.take().or(other.termination);
+    }
+
+    /// Queues the termination request, unless there is one already.
+    pub(crate) fn request_termination(&mut self, reason: TerminationReason) {
+        self.termination.get_or_insert(reason);
+    }
+}
+
+/// Runtime commands
+impl<S> EngineState<S>
+where
+    S: ClockworkState,
+{
+    /// Queues the addition of a mechanism.
+    ///
+    /// The mechanism is added between the events, and will be clinked
+    /// starting from the next event it handles.
+    /// If its ordering constraints form a cycle with the existing mechanisms,
+    /// the mechanism is dropped, and an error is logged.
+    ///
+    /// The event type must be the event type of the Clockwork,
+    /// otherwise the mechanism is dropped, and an error is logged.
+    pub fn add_mechanism<E>(
+        &mut self,
+        mechanism: impl Mechanism<S, E> + 'static,
+        ordering: MechanismOrdering,
+    ) where
+        E: ClockworkEvent,
+    {
+        let mechanism: Box<dyn Mechanism<S, E>> = Box::new(mechanism);
+        self.1
+            .mechanism_commands
+            .push(MechanismCommand::Add(Box::new((mechanism, ordering))))
+    }
+
+    /// Queues the removal of every mechanism with the label.
+    ///
+    /// Every removed mechanism receives a `Mechanism::removal` callback.
+    pub fn remove_mechanisms(&mut self, label: impl Into<MechanismLabel>) {
+        self.1
+            .mechanism_commands
+            .push(MechanismCommand::Remove(label.into()))
+    }
+
+    /// Queues the enabling of every mechanism with the label.
+    pub fn enable_mechanisms(&mut self, label: impl Into<MechanismLabel>) {
+        self.1
+            .mechanism_commands
+            .push(MechanismCommand::Enable(label.into()))
+    }
+
+    /// Queues the disabling of every mechanism with the label.
+    ///
+    /// Disabled mechanisms are kept in place, but are not clinked,
+    /// until they are enabled again.
+    pub fn disable_mechanisms(&mut self, label: impl Into<MechanismLabel>) {
+        self.1
+            .mechanism_commands
+            .push(MechanismCommand::Disable(label.into()))
+    }
+
+    /// Emits a follow-up event.
+    ///
+    /// Follow-up events are handled in the order of their emission,
+    /// right after the currently handled event, and before the control
+    /// returns to the main loop.
+    ///
+    /// The event type must be the event type of the Clockwork,
+    /// otherwise the event is dropped, and an error is logged.
+    pub fn emit_event<E>(&mut self, event: E)
+    where
+        E: ClockworkEvent,
+    {
+        self.1.events.push(Box::new(event))
+    }
+
+    /// Requests the graceful termination of the engine.
+    ///
+    /// The request is passed to the `Mechanisms` after the current event,
+    /// and the main loop terminates the engine, clinking the termination event.
+    /// The engine exits with the zero exit code.
+    ///
+    /// Only the first termination request is kept.
+    pub fn request_termination(&mut self, reason: impl Into<String>) {
+        self.request_termination_with_code(0, reason)
+    }
+
+    /// Requests the graceful termination of the engine with the exit code.
+    ///
+    /// See `EngineState::request_termination` for details.
+    pub fn request_termination_with_code(&mut self, exit_code: i32, reason: impl Into<String>) {
+        self.1.request_termination(TerminationReason::Requested {
+            reason: reason.into(),
+            exit_code,
+        })
+    }
+}
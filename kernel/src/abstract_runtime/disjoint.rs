@@ -0,0 +1,83 @@
+use super::state::ClockworkState;
+use std::any::TypeId;
+
+/// A substate, which is stored inline in a field of the superstate.
+///
+/// The trait is implemented by `#[derive(ClockworkState)]` for the type of every field,
+/// which is not skipped. Nested substates (`#[clockwork(substates(...))]`) are not fields
+/// of the superstate, and do not implement it, since they may contain each other,
+/// or live behind a pointer (e.g. in a `DynamicState`).
+///
+/// Distinct field types are distinct fields, which never overlap, so the trait is used
+/// to borrow several substates mutably at once (see `WriteCallbackGuard::get_disjoint_mut`),
+/// and to share the superstate between the parallel mechanisms.
+///
+/// # Safety
+/// `field` must return a pointer to a field of the superstate, which is stored inline,
+/// and is not returned for any other substate type.
+pub unsafe trait FieldSubstate<T>
+where
+    Self: ClockworkState,
+    T: ClockworkState,
+{
+    /// Projects the pointer to the superstate onto the pointer to the field,
+    /// without creating a reference to the superstate.
+    ///
+    /// # Safety
+    /// The superstate pointer must be valid.
+    unsafe fn field(state: *mut Self) -> *mut T;
+}
+
+/// A callback, which takes mutable references to several distinct substates at once.
+///
+/// The trait is implemented for every closure of two to four arguments
+/// `|a: &mut A, b: &mut B, ...|`, where every argument type is a `FieldSubstate`
+/// of the superstate `S`, and is used by `WriteCallbackGuard::get_disjoint_mut`.
+///
+/// Since only the fields of the superstate can be borrowed, substates of distinct
+/// types never overlap, which is checked at compile time. Repeated types are checked at runtime.
+pub trait DisjointSubstatesCallback<S, T, U>
+where
+    S: ClockworkState,
+{
+    /// Borrows the substates from the superstate, and calls the callback.
+    ///
+    /// # Panics
+    /// Panics if some substate type repeats.
+    fn call_disjoint(self, state: &mut S) -> U;
+}
+
+/// Implements the callback trait for the closures of the listed arguments
+macro_rules! disjoint_substates_callback {
+    ($($T:ident),+) => {
+        impl<S, F, U, $($T),+> DisjointSubstatesCallback<S, ($($T,)+), U> for F
+        where
+            S: ClockworkState $(+ FieldSubstate<$T>)+,
+            $($T: ClockworkState,)+
+            F: FnOnce($(&mut $T),+) -> U,
+        {
+            fn call_disjoint(self, state: &mut S) -> U {
+                assert_distinct(&[$((TypeId::of::<$T>(), std::any::type_name::<$T>())),+]);
+                let state: *mut S = state;
+                // SAFETY: the types are distinct, so the references point to distinct fields,
+                // which are projected from the same exclusively borrowed superstate
+                self($(unsafe { &mut *<S as FieldSubstate<$T>>::field(state) }),+)
+            }
+        }
+    };
+}
+
+disjoint_substates_callback!(A, B);
+disjoint_substates_callback!(A, B, C);
+disjoint_substates_callback!(A, B, C, D);
+
+/// Panics, if some type repeats
+fn assert_distinct(types: &[(TypeId, &str)]) {
+    types.iter().enumerate().for_each(|(index, (id, name))| {
+        assert!(
+            types[..index].iter().all(|(other, _)| other != id),
+            "Tried to borrow the substate {} twice",
+            name
+        )
+    })
+}
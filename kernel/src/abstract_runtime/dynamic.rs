@@ -0,0 +1,148 @@
+use super::state::{ClockworkState, Substate};
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::HashMap,
+};
+use thiserror::Error;
+
+/// A marker trait for the substates, which may be stored in the `DynamicState`.
+///
+/// Unlike `ClockworkState`, which it requires, the trait must be implemented manually.
+/// This lets `DynamicState` implement `Substate` for every dynamic substate
+/// without conflicting with the reflexive `Substate` implementation.
+///
+/// Dynamic substates must be `Send + Sync`, so that the superstate,
+/// which contains the `DynamicState`, could be shared with the parallel mechanisms.
+pub trait DynamicSubstate
+where
+    Self: ClockworkState + Send + Sync,
+{
+}
+
+/// An error, which occurs when a dynamic substate is not stored in the `DynamicState`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Substate {0} is missing in the dynamic state")]
+pub struct MissingSubstateError(pub &'static str);
+
+/// A state, which stores arbitrary dynamic substates, keyed by their types.
+///
+/// Unlike the fields of the superstate struct, dynamic substates may be inserted,
+/// and removed at runtime (e.g. by the plugins, or the scripts).
+/// The `DynamicState` implements `Substate<T>` for every `DynamicSubstate`,
+/// so it may be delegated to from the superstate with `#[clockwork(substates(...))]`.
+/// Such an access panics, if the substate is missing; the fallible accessors
+/// return `MissingSubstateError` instead.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::abstract_runtime::*;
+/// struct Script(u32);
+/// impl ClockworkState for Script {}
+/// impl DynamicSubstate for Script {}
+///
+/// #[derive(ClockworkState)]
+/// struct State {
+///     #[clockwork(substates(Script))]
+///     dynamic: DynamicState,
+/// }
+///
+/// let mut state = State {
+///     dynamic: DynamicState::default().with(Script(1)),
+/// };
+/// state.substate_mut(|script: &mut Script| script.0 += 1);
+/// assert_eq!(state.dynamic.get::<Script>().map(|script| script.0), Ok(2));
+/// ```
+#[derive(Default)]
+pub struct DynamicState(HashMap<TypeId, Box<dyn Any + Send + Sync>>);
+impl ClockworkState for DynamicState {}
+
+impl DynamicState {
+    /// Inserts the substate, returning self.
+    pub fn with<T>(mut self, substate: T) -> Self
+    where
+        T: DynamicSubstate,
+    {
+        self.insert(substate);
+        self
+    }
+
+    /// Inserts the substate, returning the replaced one.
+    pub fn insert<T>(&mut self, substate: T) -> Option<T>
+    where
+        T: DynamicSubstate,
+    {
+        self.0
+            .insert(TypeId::of::<T>(), Box::new(substate))
+            .map(|replaced| *replaced.downcast().expect("Substates are keyed by type"))
+    }
+
+    /// Removes the substate, returning it.
+    pub fn remove<T>(&mut self) -> Option<T>
+    where
+        T: DynamicSubstate,
+    {
+        self.0
+            .remove(&TypeId::of::<T>())
+            .map(|removed| *removed.downcast().expect("Substates are keyed by type"))
+    }
+
+    /// Checks, whether the substate is stored.
+    pub fn contains<T>(&self) -> bool
+    where
+        T: DynamicSubstate,
+    {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Gets a reference to the substate.
+    pub fn get<T>(&self) -> Result<&T, MissingSubstateError>
+    where
+        T: DynamicSubstate,
+    {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|substate| substate.downcast_ref())
+            .ok_or(MissingSubstateError(type_name::<T>()))
+    }
+
+    /// Gets a mutable reference to the substate.
+    pub fn get_mut<T>(&mut self) -> Result<&mut T, MissingSubstateError>
+    where
+        T: DynamicSubstate,
+    {
+        self.0
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|substate| substate.downcast_mut())
+            .ok_or(MissingSubstateError(type_name::<T>()))
+    }
+
+    /// Gets the amount of the stored substates.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks, whether no substates are stored.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Panics, if the substate is missing.
+impl<T> Substate<T> for DynamicState
+where
+    T: DynamicSubstate,
+{
+    fn substate<R>(&self, callback: impl FnOnce(&T) -> R) -> R {
+        match self.get() {
+            Ok(substate) => callback(substate),
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    fn substate_mut<R>(&mut self, callback: impl FnOnce(&mut T) -> R) -> R {
+        match self.get_mut() {
+            Ok(substate) => callback(substate),
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
@@ -1,5 +1,47 @@
 use std::{fmt::Debug, hash::Hash};
 
 /// A set of constraints, which every valid Clockwork event type should satisfy.
-pub trait ClockworkEvent: Send + Clone + Eq + Hash + Debug + 'static {}
-impl<T> ClockworkEvent for T where T: Send + Clone + Eq + Hash + Debug + 'static {}
+///
+/// An event may carry an arbitrary payload, but it must expose its kind,
+/// which is used by `Mechanisms` to route the event to the mechanisms,
+/// subscribed to it. The whole event is then delivered to these mechanisms.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::abstract_runtime::ClockworkEvent;
+/// #[derive(Clone, Debug)]
+/// enum Event {
+///     Tick,
+///     Collision { impulse: f32 },
+/// }
+///
+/// #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// enum EventKind {
+///     Tick,
+///     Collision,
+/// }
+///
+/// impl ClockworkEvent for Event {
+///     type Kind = EventKind;
+///
+///     fn kind(&self) -> EventKind {
+///         match self {
+///             Event::Tick => EventKind::Tick,
+///             Event::Collision { .. } => EventKind::Collision,
+///         }
+///     }
+/// }
+/// ```
+pub trait ClockworkEvent: Send + Clone + Debug + 'static {
+    /// A routing key of the event.
+    ///
+    /// For events without payload, this is usually the event type itself.
+    type Kind: ClockworkEventKind;
+
+    /// Gets the routing key of the event.
+    fn kind(&self) -> Self::Kind;
+}
+
+/// A set of constraints, which every valid Clockwork event kind should satisfy.
+pub trait ClockworkEventKind: Send + Clone + Eq + Hash + Debug + 'static {}
+impl<T> ClockworkEventKind for T where T: Send + Clone + Eq + Hash + Debug + 'static {}
@@ -0,0 +1,82 @@
+use super::{
+    event::ClockworkEvent,
+    state::{ClockworkState, EngineState},
+};
+use std::{
+    any::Any,
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+};
+use thiserror::Error;
+
+/// An error, which is returned by a failed mechanism.
+pub type MechanismError = Box<dyn Error + Send + Sync>;
+
+/// A mechanism, whose reaction on the event may fail.
+///
+/// Failures of the mechanism are handled by `Mechanisms`
+/// according to the `ErrorPolicy` of the mechanism.
+///
+/// Fallible mechanisms are added with `ClockworkBuilder::add_fallible_mechanism`.
+pub trait FallibleMechanism<S, E>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// Defines a fallible reaction of the mechanism on the event
+    fn try_clink(&mut self, state: &mut EngineState<S>, event: E) -> Result<(), MechanismError>;
+
+    /// Defines a set of event kinds, which this mechanism is handling.
+    ///
+    /// See `Mechanism::handled_events` for details.
+    fn handled_events(&self) -> Option<Vec<E::Kind>>;
+
+    /// Defines a reaction of the mechanism on its removal at runtime.
+    ///
+    /// See `Mechanism::removal` for details.
+    fn removal(&mut self, _state: &mut EngineState<S>) {}
+}
+
+/// A reaction of the runtime on a failed, or panicked mechanism.
+///
+/// Policies are assigned to the mechanisms by their labels
+/// with `ClockworkBuilder::error_policy`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ErrorPolicy {
+    /// Logs the error, and keeps clinking the mechanism
+    #[default]
+    Log,
+
+    /// Logs the error, and disables the mechanism
+    Disable,
+
+    /// Logs the error, and requests the termination of the engine.
+    ///
+    /// The main loop is expected to check `Mechanisms::is_termination_requested`
+    /// after every event. The engine exits with `TerminationReason::MechanismFailed`.
+    Terminate,
+}
+
+/// An error, which replaces the panic of a mechanism.
+#[derive(Error, Debug)]
+#[error("Mechanism panicked: {0}")]
+pub struct MechanismPanic(pub String);
+
+/// Invokes the mechanism, converting its panic into an error.
+pub(crate) fn catch_failure(
+    clink: impl FnOnce() -> Result<(), MechanismError>,
+) -> Result<(), MechanismError> {
+    panic::catch_unwind(AssertUnwindSafe(clink))
+        .unwrap_or_else(|payload| Err(Box::new(MechanismPanic(panic_message(payload)))))
+}
+
+/// Extracts the message from the panic payload
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic payload".to_owned(),
+        },
+    }
+}
@@ -10,22 +10,28 @@ use super::{event::ClockworkEvent, mechanism::Mechanisms, state::ClockworkState,
 /// Example:
 /// ```
 /// use std::process::exit;
+/// # use spc_clockwork_kernel::abstract_runtime::*;
 /// # use spc_clockwork_kernel::prelude::*;
 ///
 /// #[derive(Eq, PartialEq, Debug)]
 /// struct State(u8);
+/// impl ClockworkState for State {}
 ///
 /// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 /// enum Event {
 ///     Tick
 /// }
+/// impl ClockworkEvent for Event {
+///     type Kind = Self;
+///     fn kind(&self) -> Self { self.clone() }
+/// }
 ///
 /// fn main_loop(
-///     mut state: State,
+///     mut state: EngineState<State>,
 ///     mut mechanisms: Mechanisms<State, Event>
 /// ) {
 ///     loop {
-///         assert_eq!(state, State(0)); // Checking the state
+///         state.start_access().get(|s: &State| assert_eq!(s, &State(0))); // Checking the state
 ///         mechanisms.clink_event(
 ///             &mut state,
 ///             Event::Tick,
@@ -36,11 +42,12 @@ use super::{event::ClockworkEvent, mechanism::Mechanisms, state::ClockworkState,
 /// }
 ///
 ///
-/// # fn main() -> Result<(), &'static str> {
+/// # fn main() -> Result<(), String> {
 /// Clockwork::<State, Event>::builder()
-///     .with_main_loop(main_loop)
-///     .with_state(State(0))
-///     .build()?
+///     .main_loop(main_loop)
+///     .state(State(0))
+///     .build()
+///     .map_err(|e| e.to_string())?
 ///     .set_the_clock();
 /// # Ok(())
 /// # }
@@ -1,7 +1,15 @@
+use super::commands::MechanismCommand;
 use super::event::*;
+use super::fallible::*;
+use super::ordering::{self, MechanismLabel, MechanismOrdering, MechanismOrderingError};
+use super::parallel::*;
+use super::profiling::*;
+use super::recording::{Recorder, SessionRecording};
 use super::state::*;
+use super::termination::{TerminationReason, TerminationRequest};
 use itertools::*;
-use std::collections::HashMap;
+use log::*;
+use std::collections::{HashMap, VecDeque};
 
 /// Mechanism is an event handler to clockwork events.
 ///
@@ -14,32 +22,200 @@ where
     /// Defines a reaction of the mechanism on the event
     fn clink(&mut self, state: &mut EngineState<S>, event: E);
 
-    /// Defines a set of events, which this mechanism is handling.
+    /// Defines a set of event kinds, which this mechanism is handling.
     /// The method is called once during the mechanisms assembly.
     /// If None is returned, then the mechanism will be clinked upon every event.
     /// It is recommended to implement this manually, as it might save some cpu
     /// resources, especially if there is a complex event system, or big amount of
     /// mechanisms.
-    fn handled_events(&self) -> Option<Vec<E>>;
+    fn handled_events(&self) -> Option<Vec<E::Kind>>;
+
+    /// Defines a reaction of the mechanism on its removal at runtime.
+    ///
+    /// This is the last call the mechanism receives before being dropped.
+    /// The method has a default implementation, which does nothing.
+    fn removal(&mut self, _state: &mut EngineState<S>) {}
 }
 
 /// A struct, which is owned by the main loop.
 /// Its purpose is to pass events, produced by the main loop,
 /// to the mechanisms, but only if the mechanisms are subscribed
 /// to this kind of event.
+///
+/// The events are routed by their `ClockworkEvent::Kind`.
+///
+/// Mechanisms are invoked in the order, which is resolved from their
+/// ordering constraints. Mechanisms without constraints are invoked
+/// in the order of their insertion, with the mechanisms, which are
+/// handling every event, going last.
+///
+/// The set of mechanisms may be changed at runtime through the commands,
+/// queued in the `EngineState`. These commands are applied after every event,
+/// once the after-event hooks (e.g. the `GameStateMachine`) have been called.
+///
+/// Mechanisms may also emit follow-up events through the `EngineState`.
+/// These events are handled in FIFO order, before the control returns to the main loop.
+/// The events, which are emitted deeper than the maximum cascade depth, are dropped.
+///
+/// Consecutive parallel mechanisms with non-conflicting substate accesses
+/// are clinked simultaneously on the rayon thread pool.
+///
+/// Failures and panics of the mechanisms are caught, and are handled
+/// according to the `ErrorPolicy` of the failed mechanism.
 pub struct Mechanisms<S, E>
 where
     S: ClockworkState,
     E: ClockworkEvent,
 {
     /// Mechanism storage
-    all_mechanisms: Vec<Box<dyn Mechanism<S, E>>>,
+    all_mechanisms: Vec<MechanismEntry<S, E>>,
+
+    /// Ordering constraints, handled events, and activity of every mechanism
+    descriptors: Vec<MechanismDescriptor<S, E::Kind>>,
 
-    /// Mapping from event to mechanism indices
-    events_to_mechanisms: HashMap<E, Vec<usize>>,
+    /// Mapping from event kind to mechanism indices
+    events_to_mechanisms: HashMap<E::Kind, Vec<usize>>,
 
     /// A set of mechanisms, which respond to every event
     any_event_mechanisms: Vec<usize>,
+
+    /// The id of the next added mechanism
+    next_id: usize,
+
+    /// Events, which are waiting to be handled, alongside with their cascade depth
+    event_queue: VecDeque<(E, usize)>,
+
+    /// The maximum depth of follow-up events
+    max_cascade_depth: usize,
+
+    /// Error policies by mechanism label
+    error_policies: HashMap<MechanismLabel, ErrorPolicy>,
+
+    /// The error policy of the mechanisms, whose labels have no policy
+    default_error_policy: ErrorPolicy,
+
+    /// The termination request, shared with the Clockwork
+    termination: TerminationRequest,
+
+    /// Whether the termination event has been clinked
+    terminated: bool,
+
+    /// Provides access to the mechanism profiles in the state, if profiling is enabled
+    #[cfg(feature = "profiling")]
+    profiler: Option<Profiler<S>>,
+
+    /// Passes the events into the session recording in the state, if recording is enabled
+    recorder: Option<Recorder<S, E>>,
+
+    /// Hooks, which are called after every dispatched event
+    after_event_hooks: Vec<AfterEventHook<S, E>>,
+}
+
+/// A hook, which is called after every dispatched event, before the runtime commands are applied
+type AfterEventHook<S, E> = Box<dyn FnMut(&mut EngineState<S>, &E)>;
+
+/// Everything, what `Mechanisms` know about a single mechanism
+struct MechanismDescriptor<S, K>
+where
+    S: ClockworkState,
+{
+    /// The id of the mechanism, which is unique within the runtime
+    #[cfg(feature = "profiling")]
+    id: usize,
+
+    /// The first label of the mechanism, or its type name
+    name: String,
+
+    /// Ordering constraints of the mechanism
+    ordering: MechanismOrdering,
+
+    /// Event kinds, handled by the mechanism
+    handled_events: Option<Vec<K>>,
+
+    /// Whether the mechanism is clinked
+    enabled: bool,
+
+    /// Substate access of the parallel mechanism, or None for the exclusive one
+    access: Option<SubstateAccess<S>>,
+}
+
+/// A stored mechanism, which is either exclusive, fallible, or parallel
+enum MechanismEntry<S, E>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// A mechanism, which requires the whole engine state
+    Exclusive(Box<dyn Mechanism<S, E>>),
+
+    /// A mechanism, which requires the whole engine state, and may fail
+    Fallible(Box<dyn FallibleMechanism<S, E>>),
+
+    /// A mechanism, which only accesses the declared substates
+    Parallel(Box<dyn ParallelMechanism<S, E>>),
+}
+
+impl<S, E> MechanismEntry<S, E>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// Gets the event kinds, handled by the mechanism
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
+        match self {
+            MechanismEntry::Exclusive(mechanism) => mechanism.handled_events(),
+            MechanismEntry::Fallible(mechanism) => mechanism.handled_events(),
+            MechanismEntry::Parallel(mechanism) => mechanism.handled_events(),
+        }
+    }
+
+    /// Calls `clink` on the mechanism, which requires the whole engine state
+    fn try_clink(&mut self, state: &mut EngineState<S>, event: E) -> Result<(), MechanismError> {
+        match self {
+            MechanismEntry::Exclusive(mechanism) => {
+                mechanism.clink(state, event);
+                Ok(())
+            }
+            MechanismEntry::Fallible(mechanism) => mechanism.try_clink(state, event),
+            MechanismEntry::Parallel(_) => {
+                unreachable!("Parallel mechanisms are clinked in batches")
+            }
+        }
+    }
+
+    /// Calls `removal` on the mechanism
+    fn removal(&mut self, state: &mut EngineState<S>) {
+        match self {
+            MechanismEntry::Exclusive(mechanism) => mechanism.removal(state),
+            MechanismEntry::Fallible(mechanism) => mechanism.removal(state),
+            MechanismEntry::Parallel(mechanism) => mechanism.removal(state),
+        }
+    }
+}
+
+/// Results of the mechanism invocations during the dispatch of a single event
+#[derive(Default)]
+struct DispatchOutcome {
+    /// Timings of the invocations
+    samples: Samples,
+
+    /// Errors of the failed mechanisms, alongside with their ids
+    failures: Vec<(usize, MechanismError)>,
+}
+
+impl DispatchOutcome {
+    /// Invokes the mechanism, catching its failure
+    fn clink(&mut self, id: usize, clink: impl FnOnce() -> Result<(), MechanismError>) {
+        if let Err(error) = self.samples.measure(id, || catch_failure(clink)) {
+            self.failures.push((id, error))
+        }
+    }
+
+    /// Moves the results of another outcome to the end of this one
+    fn append(&mut self, mut other: DispatchOutcome) {
+        self.samples.append(other.samples);
+        self.failures.append(&mut other.failures)
+    }
 }
 
 impl<S, E> Default for Mechanisms<S, E>
@@ -50,8 +226,20 @@ where
     fn default() -> Self {
         Mechanisms {
             all_mechanisms: Default::default(),
+            descriptors: Default::default(),
             events_to_mechanisms: Default::default(),
             any_event_mechanisms: Default::default(),
+            next_id: 0,
+            event_queue: Default::default(),
+            max_cascade_depth: 16,
+            error_policies: Default::default(),
+            default_error_policy: Default::default(),
+            termination: Default::default(),
+            terminated: false,
+            #[cfg(feature = "profiling")]
+            profiler: None,
+            recorder: None,
+            after_event_hooks: Default::default(),
         }
     }
 }
@@ -63,52 +251,590 @@ where
 {
     /// Adds a mechanism to the struct.
     ///
+    /// The mechanism is not going to be clinked until the order
+    /// of the mechanisms is resolved with `Mechanisms::resolve_order`.
+    ///
     /// This method is crate-private.
     /// Use `ClockworkBuilder::add_mechanism` to add mechanism into clockwork.
-    pub(crate) fn add_mechanism(&mut self, mechanism: impl Mechanism<S, E> + 'static) {
+    pub(crate) fn add_mechanism<M>(&mut self, mechanism: M, ordering: MechanismOrdering)
+    where
+        M: Mechanism<S, E> + 'static,
+    {
+        self.add_entry(
+            MechanismEntry::Exclusive(Box::new(mechanism)),
+            std::any::type_name::<M>(),
+            ordering,
+            None,
+        )
+    }
+
+    /// Adds a fallible mechanism to the struct.
+    ///
+    /// The mechanism is not going to be clinked until the order
+    /// of the mechanisms is resolved with `Mechanisms::resolve_order`.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::add_fallible_mechanism` to add mechanism into clockwork.
+    pub(crate) fn add_fallible_mechanism<M>(&mut self, mechanism: M, ordering: MechanismOrdering)
+    where
+        M: FallibleMechanism<S, E> + 'static,
+    {
+        self.add_entry(
+            MechanismEntry::Fallible(Box::new(mechanism)),
+            std::any::type_name::<M>(),
+            ordering,
+            None,
+        )
+    }
+
+    /// Adds a parallel mechanism to the struct.
+    ///
+    /// The mechanism is not going to be clinked until the order
+    /// of the mechanisms is resolved with `Mechanisms::resolve_order`.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::add_parallel_mechanism` to add mechanism into clockwork.
+    pub(crate) fn add_parallel_mechanism<M>(&mut self, mechanism: M, ordering: MechanismOrdering)
+    where
+        M: ParallelMechanism<S, E> + 'static,
+        S: Send + Sync,
+    {
+        let access = mechanism.substate_access();
+        self.add_entry(
+            MechanismEntry::Parallel(Box::new(mechanism)),
+            std::any::type_name::<M>(),
+            ordering,
+            Some(access),
+        )
+    }
+
+    /// Adds a mechanism entry to the struct.
+    ///
+    /// The mechanism is named after its first label,
+    /// or after its type name, if it has no labels.
+    /// The name is only used for display, and the mechanism is identified by its unique id.
+    fn add_entry(
+        &mut self,
+        mechanism: MechanismEntry<S, E>,
+        type_name: &str,
+        ordering: MechanismOrdering,
+        access: Option<SubstateAccess<S>>,
+    ) {
+        let handled_events = mechanism
+            .handled_events()
+            .map(IntoIterator::into_iter)
+            .map(Itertools::unique)
+            .map(Iterator::collect);
+        let name = ordering
+            .labels()
+            .first()
+            .map_or(type_name, String::as_str)
+            .to_owned();
+        let id = self.next_id;
+        self.next_id += 1;
+        debug!("Adding mechanism {} with id {}", name, id);
+        self.descriptors.push(MechanismDescriptor {
+            #[cfg(feature = "profiling")]
+            id,
+            name,
+            ordering,
+            handled_events,
+            enabled: true,
+            access,
+        });
+        self.all_mechanisms.push(mechanism);
+    }
+
+    /// Sets the maximum depth of follow-up events.
+    ///
+    /// Events, emitted during the handling of the main loop events, have depth 1,
+    /// events, emitted during the handling of these events, have depth 2, and so on.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::max_cascade_depth` to configure clockwork.
+    pub(crate) fn set_max_cascade_depth(&mut self, depth: usize) {
+        self.max_cascade_depth = depth
+    }
+
+    /// Sets the error policy of the mechanisms with the label.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::error_policy` to configure clockwork.
+    pub(crate) fn set_error_policy(&mut self, label: MechanismLabel, policy: ErrorPolicy) {
+        self.error_policies.insert(label, policy);
+    }
+
+    /// Sets the error policy of the mechanisms, whose labels have no policy.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::default_error_policy` to configure clockwork.
+    pub(crate) fn set_default_error_policy(&mut self, policy: ErrorPolicy) {
+        self.default_error_policy = policy
+    }
+
+    /// Checks, whether the termination of the engine has been requested,
+    /// either by a mechanism, by a failed mechanism with the `ErrorPolicy::Terminate`,
+    /// or by the main loop.
+    ///
+    /// Main loops should check this after every event,
+    /// and terminate the engine gracefully with `Mechanisms::clink_termination`.
+    pub fn is_termination_requested(&self) -> bool {
+        self.termination.reason().is_some()
+    }
+
+    /// Gets the reason of the requested termination.
+    pub fn termination_reason(&self) -> Option<TerminationReason> {
+        self.termination.reason()
+    }
+
+    /// Requests the termination of the engine.
+    ///
+    /// This is meant for the main loops, which are translating their own
+    /// termination causes (e.g. a closed window). Mechanisms should use
+    /// `EngineState::request_termination` instead.
+    ///
+    /// Only the first termination request is kept.
+    pub fn request_termination(&mut self, reason: TerminationReason) {
+        match self.termination.request(reason.clone()) {
+            true => info!("Termination requested: {}", reason),
+            false => debug!("Ignored termination request: {}", reason),
+        }
+    }
+
+    /// Clinks the termination event, unless it has already been clinked.
+    ///
+    /// If the termination has not been requested, requests it
+    /// with `TerminationReason::MainLoopReturned`.
+    /// After the termination, the events are no longer clinked.
+    ///
+    /// Returns false, if the termination event has already been clinked.
+    pub fn clink_termination(&mut self, state: &mut EngineState<S>, event: E) -> bool {
+        if self.terminated {
+            return false;
+        }
+        if !self.is_termination_requested() {
+            self.request_termination(TerminationReason::MainLoopReturned)
+        }
+        self.clink_event(state, event);
+        self.terminated = true;
+        true
+    }
+
+    /// Checks, whether the termination event has been clinked.
+    pub fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+
+    /// Gets the termination request, which is shared with the Clockwork.
+    ///
+    /// This method is crate-private, and is used by `Clockwork::set_the_clock`
+    /// to obtain the reason of the termination after the main loop returns.
+    pub(crate) fn termination_request(&self) -> TerminationRequest {
+        self.termination.clone()
+    }
+
+    /// Enables the profiling of the mechanisms.
+    ///
+    /// The timings are recorded into the `MechanismProfiles` substate.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::profile_mechanisms` to configure clockwork.
+    #[cfg(feature = "profiling")]
+    pub(crate) fn enable_profiling(&mut self)
+    where
+        S: Substate<MechanismProfiles>,
+    {
+        self.profiler = Some(|state, callback| state.substate_mut(|profiles| callback(profiles)))
+    }
+
+    /// Enables the recording of the events into the `SessionRecording` substate.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::record_session` to configure clockwork.
+    pub(crate) fn enable_recording<I>(&mut self)
+    where
+        S: Substate<SessionRecording<E, I>>,
+        I: 'static,
+    {
+        self.recorder = Some(|state, event| {
+            state.substate_mut(|recording: &mut SessionRecording<E, I>| {
+                recording.record_event(event)
+            })
+        })
+    }
+
+    /// Adds the hook, which is called after every dispatched event,
+    /// once all the mechanisms have been clinked.
+    ///
+    /// This method is crate-private.
+    /// Use `ClockworkBuilder::add_game_state_machine` to configure clockwork.
+    pub(crate) fn add_after_event_hook(
+        &mut self,
+        hook: impl FnMut(&mut EngineState<S>, &E) + 'static,
+    ) {
+        self.after_event_hooks.push(Box::new(hook))
+    }
+
+    /// Resolves the order of the mechanisms from their ordering constraints,
+    /// then rebuilds the mapping from events to mechanisms.
+    ///
+    /// This method is crate-private, and is called by `ClockworkBuilder::build`.
+    pub(crate) fn resolve_order(&mut self) -> Result<(), MechanismOrderingError> {
         let Self {
-            all_mechanisms,
+            descriptors,
             events_to_mechanisms,
             any_event_mechanisms,
             ..
         } = self;
-        let mechanism_id = all_mechanisms.len();
-        mechanism
-            .handled_events()
-            .map(IntoIterator::into_iter)
-            .map(Itertools::unique)
-            .map(|it| {
-                it.for_each(|event| {
-                    events_to_mechanisms
-                        .entry(event.clone())
-                        .or_default()
-                        .push(mechanism_id)
+        let order = ordering::resolve_order(
+            &descriptors
+                .iter()
+                .enumerate()
+                .map(|(id, descriptor)| {
+                    (
+                        &descriptor.ordering,
+                        (descriptor.handled_events.is_none(), id),
+                    )
                 })
+                .collect_vec(),
+        )?;
+        *any_event_mechanisms = order
+            .iter()
+            .cloned()
+            .filter(|&id| descriptors[id].handled_events.is_none())
+            .collect();
+        *events_to_mechanisms = descriptors
+            .iter()
+            .filter_map(|descriptor| descriptor.handled_events.as_ref())
+            .flatten()
+            .unique()
+            .map(|event| {
+                (
+                    event.clone(),
+                    order
+                        .iter()
+                        .cloned()
+                        .filter(|&id| match &descriptors[id].handled_events {
+                            Some(handled_events) => handled_events.contains(event),
+                            None => true,
+                        })
+                        .collect(),
+                )
             })
-            .or_else(|| Some(any_event_mechanisms.push(mechanism_id)))
-            .map(|_| all_mechanisms.push(Box::from(mechanism)))
-            .unwrap()
+            .collect();
+        Ok(())
     }
 
     /// Gets a mutable reference to the state, and event,
     /// then calls `clink` on every `Mechanism`, and `ReadMechanism`
     /// instance, which has been subscribed to the event of this kind.
+    ///
+    /// After the event is handled, calls the after-event hooks, applies the runtime commands,
+    /// which have been queued by the mechanisms, and then handles
+    /// the follow-up events in the same manner.
+    ///
+    /// If the session recording is enabled, the event is recorded
+    /// (but not its follow-up events, which are reproduced by the mechanisms).
+    ///
+    /// After the termination event has been clinked, the events are dropped.
     pub fn clink_event(&mut self, state: &mut EngineState<S>, event: E) {
+        if self.terminated {
+            warn!("Dropped event {:?}: the engine has been terminated", event);
+            return;
+        }
+        if let Some(recorder) = self.recorder {
+            recorder(&mut state.0, &event)
+        }
+        self.event_queue.push_back((event, 0));
+        while let Some((event, depth)) = self.event_queue.pop_front() {
+            self.dispatch_event(state, &event);
+            self.after_event_hooks
+                .iter_mut()
+                .for_each(|hook| hook(state, &event));
+            self.apply_commands(state);
+            self.enqueue_follow_up_events(state, depth + 1);
+        }
+    }
+
+    /// Calls `clink` on every enabled mechanism, which handles the event.
+    ///
+    /// Consecutive parallel mechanisms are collected into a batch,
+    /// until one of them conflicts with the batch, or an exclusive mechanism
+    /// is met. Then the batch is clinked simultaneously.
+    fn dispatch_event(&mut self, state: &mut EngineState<S>, event: &E) {
         let Self {
             ref mut all_mechanisms,
+            ref descriptors,
             ref events_to_mechanisms,
             ref any_event_mechanisms,
+            ..
         } = self;
+        let mut batch = Vec::new();
+        let mut batch_access = SubstateAccess::default();
+        let mut outcome = DispatchOutcome::default();
         events_to_mechanisms
-            .get(&event)
-            .map(|x| x.clone())
-            .unwrap_or_default()
+            .get(&event.kind())
+            .unwrap_or(any_event_mechanisms)
+            .iter()
+            .filter(|&&id| descriptors[id].enabled)
+            .for_each(|&id| match &descriptors[id].access {
+                Some(access) => {
+                    if batch_access.conflicts_with(access) {
+                        Self::clink_batch(
+                            all_mechanisms,
+                            descriptors,
+                            &mut batch,
+                            &mut outcome,
+                            state,
+                            event,
+                        );
+                        batch_access = Default::default();
+                    }
+                    batch_access = std::mem::take(&mut batch_access).union(access);
+                    batch.push(id);
+                }
+                None => {
+                    Self::clink_batch(
+                        all_mechanisms,
+                        descriptors,
+                        &mut batch,
+                        &mut outcome,
+                        state,
+                        event,
+                    );
+                    batch_access = Default::default();
+                    let mechanism = unsafe { all_mechanisms.get_unchecked_mut(id) };
+                    outcome.clink(id, || mechanism.try_clink(state, event.clone()))
+                }
+            });
+        Self::clink_batch(
+            all_mechanisms,
+            descriptors,
+            &mut batch,
+            &mut outcome,
+            state,
+            event,
+        );
+        #[cfg(feature = "profiling")]
+        self.record_samples(state, event, &outcome.samples);
+        self.handle_failures(outcome.failures);
+    }
+
+    /// Clinks a batch of parallel mechanisms simultaneously,
+    /// then merges their runtime commands into the engine state
+    /// in the order of the mechanisms.
+    ///
+    /// The batch is emptied afterwards.
+    fn clink_batch(
+        all_mechanisms: &mut [MechanismEntry<S, E>],
+        descriptors: &[MechanismDescriptor<S, E::Kind>],
+        batch: &mut Vec<usize>,
+        outcome: &mut DispatchOutcome,
+        state: &mut EngineState<S>,
+        event: &E,
+    ) {
+        let superstate: *mut S = &mut state.0;
+        let mechanisms = all_mechanisms.as_mut_ptr();
+        let mut views = batch
+            .drain(..)
+            .filter_map(|id| {
+                // SAFETY: the ids of the batch are distinct, and their accesses do not conflict
+                match unsafe { &mut *mechanisms.add(id) } {
+                    MechanismEntry::Parallel(mechanism) => Some((
+                        id,
+                        mechanism,
+                        unsafe {
+                            SharedEngineState::new(
+                                superstate,
+                                descriptors[id].access.clone().unwrap_or_default(),
+                            )
+                        },
+                        DispatchOutcome::default(),
+                    )),
+                    _ => None,
+                }
+            })
+            .collect_vec();
+        match views.as_mut_slice() {
+            [] => {}
+            [(id, mechanism, view, outcome)] => outcome.clink(*id, || {
+                mechanism.clink(view, event.clone());
+                Ok(())
+            }),
+            views => {
+                let events = views.iter().map(|_| event.clone()).collect_vec();
+                rayon::scope(|scope| {
+                    views.iter_mut().zip(events).for_each(
+                        |((id, mechanism, view, outcome), event)| {
+                            scope.spawn(move |_| {
+                                outcome.clink(*id, || {
+                                    mechanism.clink(view, event);
+                                    Ok(())
+                                })
+                            })
+                        },
+                    )
+                })
+            }
+        }
+        views.into_iter().for_each(|(_, _, view, view_outcome)| {
+            state.1.append(view.commands);
+            outcome.append(view_outcome)
+        })
+    }
+
+    /// Records the samples of the mechanisms, clinked upon the event,
+    /// into the `MechanismProfiles` substate.
+    #[cfg(feature = "profiling")]
+    fn record_samples(&self, state: &mut EngineState<S>, event: &E, samples: &Samples) {
+        if let Some(profiler) = self.profiler {
+            let event = format!("{:?}", event.kind());
+            profiler(&mut state.0, &mut |profiles| {
+                samples.0.iter().for_each(|(id, sample)| {
+                    let descriptor = &self.descriptors[*id];
+                    profiles.record(descriptor.id, &descriptor.name, &event, sample)
+                })
+            })
+        }
+    }
+
+    /// Applies the error policies of the failed mechanisms.
+    fn handle_failures(&mut self, failures: Vec<(usize, MechanismError)>) {
+        let Self {
+            descriptors,
+            error_policies,
+            default_error_policy,
+            termination,
+            ..
+        } = self;
+        failures.into_iter().for_each(|(id, error)| {
+            let descriptor = &mut descriptors[id];
+            let policy = descriptor
+                .ordering
+                .labels()
+                .iter()
+                .find_map(|label| error_policies.get(label))
+                .cloned()
+                .unwrap_or(*default_error_policy);
+            error!("Mechanism {} failed: {}", descriptor.name, error);
+            match policy {
+                ErrorPolicy::Log => {}
+                ErrorPolicy::Disable => {
+                    warn!("Disabling mechanism {}", descriptor.name);
+                    descriptor.enabled = false
+                }
+                ErrorPolicy::Terminate => {
+                    warn!("Mechanism {} requested termination", descriptor.name);
+                    termination
+                        .request(TerminationReason::MechanismFailed(descriptor.name.clone()));
+                }
+            }
+        })
+    }
+
+    /// Moves the events, emitted by the mechanisms, into the event queue.
+    fn enqueue_follow_up_events(&mut self, state: &mut EngineState<S>, depth: usize) {
+        let Self {
+            event_queue,
+            max_cascade_depth,
+            ..
+        } = self;
+        std::mem::take(&mut state.1.events)
             .into_iter()
-            .chain(any_event_mechanisms.iter().cloned())
-            .for_each(|id| unsafe {
-                all_mechanisms
-                    .get_unchecked_mut(id)
-                    .clink(state, event.clone())
+            .filter_map(|event| match event.downcast::<E>() {
+                Ok(event) => Some(*event),
+                Err(_) => {
+                    error!("Dropped follow-up event: its type differs from the runtime one");
+                    None
+                }
+            })
+            .for_each(|event| match depth > *max_cascade_depth {
+                true => error!(
+                    "Dropped follow-up event {:?}: maximum cascade depth {} exceeded",
+                    event, max_cascade_depth
+                ),
+                false => event_queue.push_back((event, depth)),
             })
     }
+
+    /// Applies the runtime commands, queued in the engine state.
+    ///
+    /// Commands, queued by removed mechanisms during their `removal` callback,
+    /// are applied as well.
+    fn apply_commands(&mut self, state: &mut EngineState<S>) {
+        while !state.1.mechanism_commands.is_empty() {
+            std::mem::take(&mut state.1.mechanism_commands)
+                .into_iter()
+                .for_each(|command| match command {
+                    MechanismCommand::Add(mechanism) => self.apply_addition(mechanism),
+                    MechanismCommand::Remove(label) => self.apply_removal(state, &label),
+                    MechanismCommand::Enable(label) => self.set_enabled(&label, true),
+                    MechanismCommand::Disable(label) => self.set_enabled(&label, false),
+                })
+        }
+        if let Some(reason) = state.1.termination.take() {
+            self.request_termination(reason)
+        }
+    }
+
+    /// Adds a type-erased mechanism, then resolves the order.
+    fn apply_addition(&mut self, mechanism: Box<dyn std::any::Any>) {
+        match mechanism.downcast::<(Box<dyn Mechanism<S, E>>, MechanismOrdering)>() {
+            Ok(mechanism) => {
+                let (mechanism, ordering) = *mechanism;
+                self.add_entry(
+                    MechanismEntry::Exclusive(mechanism),
+                    "runtime mechanism",
+                    ordering,
+                    None,
+                );
+                if let Err(error) = self.resolve_order() {
+                    error!("Failed to add mechanism: {}", error);
+                    self.all_mechanisms.pop();
+                    self.descriptors.pop();
+                    self.resolve_order()
+                        .expect("Mechanism order must be resolvable after a rollback");
+                }
+            }
+            Err(_) => {
+                error!("Failed to add mechanism: its event type differs from the runtime one")
+            }
+        }
+    }
+
+    /// Removes every mechanism with the label, then resolves the order.
+    fn apply_removal(&mut self, state: &mut EngineState<S>, label: &str) {
+        let (removed, kept) = std::mem::take(&mut self.all_mechanisms)
+            .into_iter()
+            .zip(std::mem::take(&mut self.descriptors))
+            .partition::<Vec<_>, _>(|(_, descriptor)| Self::is_labeled(descriptor, label));
+        debug!(
+            "Removing {} mechanisms with label {:?}",
+            removed.len(),
+            label
+        );
+        kept.into_iter().for_each(|(mechanism, descriptor)| {
+            self.all_mechanisms.push(mechanism);
+            self.descriptors.push(descriptor);
+        });
+        self.resolve_order()
+            .expect("Mechanism order must be resolvable after a removal");
+        removed.into_iter().for_each(|(mut mechanism, descriptor)| {
+            debug!("Removing mechanism {}", descriptor.name);
+            mechanism.removal(state)
+        });
+    }
+
+    /// Enables or disables every mechanism with the label.
+    fn set_enabled(&mut self, label: &str, enabled: bool) {
+        self.descriptors
+            .iter_mut()
+            .filter(|descriptor| Self::is_labeled(descriptor, label))
+            .for_each(|descriptor| descriptor.enabled = enabled)
+    }
+
+    /// Checks, whether the mechanism has the label.
+    fn is_labeled(descriptor: &MechanismDescriptor<S, E::Kind>, label: &str) -> bool {
+        descriptor.ordering.labels().iter().any(|own| own == label)
+    }
 }
@@ -0,0 +1,158 @@
+use std::collections::{BTreeSet, HashMap};
+
+/// A label, which can be attached to a mechanism,
+/// and then referenced by the ordering constraints of other mechanisms.
+pub type MechanismLabel = String;
+
+/// A set of ordering constraints of a single mechanism.
+///
+/// A mechanism may have any amount of labels, and may be required
+/// to run before or after every mechanism, which has some label.
+/// Constraints, which reference labels that no mechanism has, are ignored.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::abstract_runtime::MechanismOrdering;
+/// let ordering = MechanismOrdering::labeled("physics")
+///     .after("input")
+///     .before("rendering");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MechanismOrdering {
+    /// Labels of the mechanism
+    labels: Vec<MechanismLabel>,
+
+    /// Labels of the mechanisms, which must run after this one
+    before: Vec<MechanismLabel>,
+
+    /// Labels of the mechanisms, which must run before this one
+    after: Vec<MechanismLabel>,
+}
+
+/// An error, which occurs when mechanism ordering constraints cannot be satisfied.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum MechanismOrderingError {
+    /// The constraints form a cycle.
+    ///
+    /// Contains the labels (or the insertion indices of unlabeled mechanisms),
+    /// which either participate in the cycle, or depend on it.
+    #[error("Mechanism ordering constraints form a cycle between {0:?}")]
+    Cycle(Vec<String>),
+}
+
+impl MechanismOrdering {
+    /// Creates an ordering with a single label and no constraints.
+    pub fn labeled(label: impl Into<MechanismLabel>) -> Self {
+        Self::default().label(label)
+    }
+
+    /// Adds a label to the mechanism.
+    pub fn label(mut self, label: impl Into<MechanismLabel>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// Requires the mechanism to run before every mechanism with the label.
+    pub fn before(mut self, label: impl Into<MechanismLabel>) -> Self {
+        self.before.push(label.into());
+        self
+    }
+
+    /// Requires the mechanism to run after every mechanism with the label.
+    pub fn after(mut self, label: impl Into<MechanismLabel>) -> Self {
+        self.after.push(label.into());
+        self
+    }
+
+    /// Gets the labels of the mechanism.
+    pub fn labels(&self) -> &[MechanismLabel] {
+        &self.labels
+    }
+}
+
+/// Resolves the execution order of the mechanisms.
+///
+/// Takes the ordering constraints and the tie-breaking priority of every mechanism,
+/// and returns the mechanism indices in the order of their execution.
+/// Whenever several mechanisms can run next, the one with the lowest priority is chosen,
+/// so that in the absence of constraints the order is defined by the priority alone.
+pub(crate) fn resolve_order<P>(
+    orderings: &[(&MechanismOrdering, P)],
+) -> Result<Vec<usize>, MechanismOrderingError>
+where
+    P: Ord + Clone,
+{
+    /* ---- INDEXING LABELS ---- */
+    let mut labeled = HashMap::<&str, Vec<usize>>::new();
+    orderings
+        .iter()
+        .enumerate()
+        .for_each(|(id, (ordering, _))| {
+            ordering
+                .labels
+                .iter()
+                .for_each(|label| labeled.entry(label.as_str()).or_default().push(id))
+        });
+
+    /* ---- BUILDING DEPENDENCY GRAPH ---- */
+    let mut successors = vec![BTreeSet::<usize>::new(); orderings.len()];
+    orderings
+        .iter()
+        .enumerate()
+        .for_each(|(id, (ordering, _))| {
+            let lookup = |label: &MechanismLabel| {
+                labeled
+                    .get(label.as_str())
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .filter(move |&other| other != id)
+            };
+            ordering.before.iter().flat_map(lookup).for_each(|other| {
+                successors[id].insert(other);
+            });
+            ordering.after.iter().flat_map(lookup).for_each(|other| {
+                successors[other].insert(id);
+            });
+        });
+    let mut in_degrees = vec![0usize; orderings.len()];
+    successors
+        .iter()
+        .flatten()
+        .for_each(|&id| in_degrees[id] += 1);
+
+    /* ---- SORTING TOPOLOGICALLY ---- */
+    let mut ready = in_degrees
+        .iter()
+        .enumerate()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(id, _)| (orderings[id].1.clone(), id))
+        .collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(orderings.len());
+    while let Some(next @ (_, id)) = ready.iter().next().cloned() {
+        ready.remove(&next);
+        order.push(id);
+        successors[id].iter().for_each(|&other| {
+            in_degrees[other] -= 1;
+            if in_degrees[other] == 0 {
+                ready.insert((orderings[other].1.clone(), other));
+            }
+        });
+    }
+
+    /* ---- REPORTING CYCLES ---- */
+    match order.len() == orderings.len() {
+        true => Ok(order),
+        false => Err(MechanismOrderingError::Cycle(
+            in_degrees
+                .iter()
+                .enumerate()
+                .filter(|(_, &degree)| degree > 0)
+                .map(|(id, _)| match orderings[id].0.labels.first() {
+                    Some(label) => label.clone(),
+                    None => format!("#{}", id),
+                })
+                .collect(),
+        )),
+    }
+}
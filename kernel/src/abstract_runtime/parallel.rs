@@ -0,0 +1,279 @@
+use super::{
+    commands::RuntimeCommands,
+    disjoint::FieldSubstate,
+    event::ClockworkEvent,
+    state::{ClockworkState, EngineState},
+    termination::TerminationReason,
+};
+use std::{any::TypeId, fmt, marker::PhantomData};
+
+/// A declaration of substates of the superstate `S`, which a mechanism reads and writes.
+///
+/// Only the fields of the superstate (see `FieldSubstate`) can be declared,
+/// so the declared substates never contain each other, and the declarations
+/// of distinct types never conflict.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::abstract_runtime::{ClockworkState, SubstateAccess};
+/// struct Physics;
+/// impl ClockworkState for Physics {}
+/// struct Statistics;
+/// impl ClockworkState for Statistics {}
+///
+/// #[derive(ClockworkState)]
+/// struct State {
+///     physics: Physics,
+///     statistics: Statistics,
+/// }
+///
+/// let access = SubstateAccess::<State>::default()
+///     .write::<Physics>()
+///     .read::<Statistics>();
+/// assert!(access.conflicts_with(&SubstateAccess::default().read::<Physics>()));
+/// assert!(!access.conflicts_with(&SubstateAccess::default().read::<Statistics>()));
+/// ```
+pub struct SubstateAccess<S>
+where
+    S: ClockworkState,
+{
+    /// Substates, which are only read
+    reads: Vec<TypeId>,
+
+    /// Substates, which are read and written
+    writes: Vec<TypeId>,
+
+    /// The superstate, whose fields are declared
+    phantom_data: PhantomData<fn() -> S>,
+}
+
+impl<S> SubstateAccess<S>
+where
+    S: ClockworkState,
+{
+    /// Declares a read-only access to the substate.
+    pub fn read<T>(mut self) -> Self
+    where
+        T: ClockworkState,
+        S: FieldSubstate<T>,
+    {
+        self.reads.push(TypeId::of::<T>());
+        self
+    }
+
+    /// Declares a read-write access to the substate.
+    pub fn write<T>(mut self) -> Self
+    where
+        T: ClockworkState,
+        S: FieldSubstate<T>,
+    {
+        self.writes.push(TypeId::of::<T>());
+        self
+    }
+
+    /// Checks, whether two mechanisms with these accesses cannot run simultaneously,
+    /// i.e. whether one of them writes a substate, which the other one accesses.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        let writes_any = |access: &Self, accessed: &[TypeId]| {
+            access.writes.iter().any(|id| accessed.contains(id))
+        };
+        writes_any(self, &other.reads)
+            || writes_any(self, &other.writes)
+            || writes_any(other, &self.reads)
+    }
+
+    /// Merges two accesses.
+    pub(crate) fn union(mut self, other: &Self) -> Self {
+        self.reads.extend(other.reads.iter().cloned());
+        self.writes.extend(other.writes.iter().cloned());
+        self
+    }
+
+    /// Checks, whether the substate may be read.
+    fn can_read(&self, id: TypeId) -> bool {
+        self.reads.contains(&id) || self.can_write(id)
+    }
+
+    /// Checks, whether the substate may be written.
+    fn can_write(&self, id: TypeId) -> bool {
+        self.writes.contains(&id)
+    }
+}
+
+impl<S> Default for SubstateAccess<S>
+where
+    S: ClockworkState,
+{
+    fn default() -> Self {
+        Self {
+            reads: Default::default(),
+            writes: Default::default(),
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<S> Clone for SubstateAccess<S>
+where
+    S: ClockworkState,
+{
+    fn clone(&self) -> Self {
+        Self {
+            reads: self.reads.clone(),
+            writes: self.writes.clone(),
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<S> fmt::Debug for SubstateAccess<S>
+where
+    S: ClockworkState,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubstateAccess")
+            .field("reads", &self.reads)
+            .field("writes", &self.writes)
+            .finish()
+    }
+}
+
+/// A view of the engine state, which is given to the parallel mechanisms.
+///
+/// Only allows access to the substates, which the mechanism has declared
+/// in its `SubstateAccess`. The substates are projected from the superstate
+/// as its fields, so the views never reference the whole superstate.
+pub struct SharedEngineState<'a, S>
+where
+    S: ClockworkState,
+{
+    /// The superstate, which is shared between simultaneously running mechanisms
+    state: *mut S,
+
+    /// Substates, which the mechanism is allowed to access
+    access: SubstateAccess<S>,
+
+    /// Runtime commands of the mechanism, which are merged after the dispatch
+    pub(crate) commands: RuntimeCommands,
+
+    /// The lifetime of the superstate borrow
+    phantom_data: PhantomData<&'a mut S>,
+}
+
+// SAFETY: parallel mechanisms can only be added, when the superstate is `Send + Sync`,
+// and the runtime commands of the view only contain events, which are `Send`
+unsafe impl<S> Send for SharedEngineState<'_, S> where S: ClockworkState {}
+
+impl<'a, S> SharedEngineState<'a, S>
+where
+    S: ClockworkState,
+{
+    /// Creates a view of the superstate.
+    ///
+    /// # Safety
+    /// The pointer must stay valid for `'a`, and the views, which exist at the same time,
+    /// must have non-conflicting accesses.
+    pub(crate) unsafe fn new(state: *mut S, access: SubstateAccess<S>) -> Self {
+        Self {
+            state,
+            access,
+            commands: Default::default(),
+            phantom_data: Default::default(),
+        }
+    }
+
+    /// Executes the callback, which takes a reference to a substate,
+    /// returning its result.
+    ///
+    /// # Panics
+    /// Panics if the substate has not been declared in the `SubstateAccess`.
+    pub fn get<T, U>(&self, callback: impl FnOnce(&T) -> U) -> U
+    where
+        T: ClockworkState,
+        S: FieldSubstate<T>,
+    {
+        assert!(
+            self.access.can_read(TypeId::of::<T>()),
+            "Tried to read the substate {}, which has not been declared",
+            std::any::type_name::<T>()
+        );
+        // SAFETY: the field is declared, so no simultaneously running mechanism writes it
+        callback(unsafe { &*<S as FieldSubstate<T>>::field(self.state) })
+    }
+
+    /// Executes the callback, which takes a mutable reference to a substate,
+    /// returning its result.
+    ///
+    /// # Panics
+    /// Panics if the substate has not been declared as written in the `SubstateAccess`.
+    pub fn get_mut<T, U>(&mut self, callback: impl FnOnce(&mut T) -> U) -> U
+    where
+        T: ClockworkState,
+        S: FieldSubstate<T>,
+    {
+        assert!(
+            self.access.can_write(TypeId::of::<T>()),
+            "Tried to write the substate {}, which has not been declared as written",
+            std::any::type_name::<T>()
+        );
+        // SAFETY: the field is declared as written, so no simultaneously running mechanism
+        // accesses it
+        callback(unsafe { &mut *<S as FieldSubstate<T>>::field(self.state) })
+    }
+
+    /// Emits a follow-up event.
+    ///
+    /// See `EngineState::emit_event` for details.
+    pub fn emit_event<E>(&mut self, event: E)
+    where
+        E: ClockworkEvent,
+    {
+        self.commands.events.push(Box::new(event))
+    }
+
+    /// Requests the graceful termination of the engine.
+    ///
+    /// See `EngineState::request_termination` for details.
+    pub fn request_termination(&mut self, reason: impl Into<String>) {
+        self.request_termination_with_code(0, reason)
+    }
+
+    /// Requests the graceful termination of the engine with the exit code.
+    ///
+    /// See `EngineState::request_termination` for details.
+    pub fn request_termination_with_code(&mut self, exit_code: i32, reason: impl Into<String>) {
+        self.commands
+            .request_termination(TerminationReason::Requested {
+                reason: reason.into(),
+                exit_code,
+            })
+    }
+}
+
+/// A mechanism, which declares the substates it accesses, and therefore
+/// may be clinked simultaneously with other parallel mechanisms,
+/// as long as their accesses do not conflict.
+///
+/// Parallel mechanisms are added with `ClockworkBuilder::add_parallel_mechanism`.
+pub trait ParallelMechanism<S, E>: Send
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// Defines a reaction of the mechanism on the event
+    fn clink(&mut self, state: &mut SharedEngineState<'_, S>, event: E);
+
+    /// Defines a set of event kinds, which this mechanism is handling.
+    ///
+    /// See `Mechanism::handled_events` for details.
+    fn handled_events(&self) -> Option<Vec<E::Kind>>;
+
+    /// Defines a set of substates, which this mechanism accesses.
+    /// The method is called once during the mechanisms assembly.
+    fn substate_access(&self) -> SubstateAccess<S>;
+
+    /// Defines a reaction of the mechanism on its removal at runtime.
+    ///
+    /// See `Mechanism::removal` for details.
+    fn removal(&mut self, _state: &mut EngineState<S>) {}
+}
@@ -0,0 +1,311 @@
+#[cfg(not(feature = "profiling"))]
+pub(crate) use disabled::*;
+#[cfg(feature = "profiling")]
+pub use enabled::*;
+
+/// Mechanism profiling, which is compiled with the `profiling` feature
+#[cfg(feature = "profiling")]
+mod enabled {
+    use crate::abstract_runtime::ClockworkState;
+    use std::{
+        collections::{HashMap, VecDeque},
+        fmt::Write,
+        time::{Duration, Instant},
+    };
+
+    /// A single measured invocation of a mechanism
+    #[derive(Clone, Debug)]
+    pub(crate) struct MechanismSample {
+        /// The moment, when the invocation started
+        start: Instant,
+
+        /// The duration of the invocation
+        duration: Duration,
+
+        /// The thread, which invoked the mechanism (0 for the main loop thread)
+        thread: usize,
+    }
+
+    /// A function, which provides access to the `MechanismProfiles` substate
+    pub(crate) type Profiler<S> = fn(&mut S, &mut dyn FnMut(&mut MechanismProfiles));
+
+    /// Samples, collected during the dispatch of a single event,
+    /// alongside with the ids of the measured mechanisms
+    #[derive(Default)]
+    pub(crate) struct Samples(pub(crate) Vec<(usize, MechanismSample)>);
+
+    impl Samples {
+        /// Invokes the mechanism, measuring the wall-clock time of the invocation
+        pub(crate) fn measure<R>(&mut self, id: usize, clink: impl FnOnce() -> R) -> R {
+            let start = Instant::now();
+            let result = clink();
+            self.0.push((
+                id,
+                MechanismSample {
+                    start,
+                    duration: start.elapsed(),
+                    thread: rayon::current_thread_index().map_or(0, |thread| thread + 1),
+                },
+            ));
+            result
+        }
+
+        /// Moves the samples of another collection to the end of this one
+        pub(crate) fn append(&mut self, mut other: Samples) {
+            self.0.append(&mut other.0)
+        }
+    }
+
+    /// Timing statistics of a single mechanism.
+    #[derive(Clone, Debug, Default)]
+    pub struct MechanismTimings {
+        /// The name of the mechanism, which is only used for display
+        name: String,
+
+        /// Durations of the most recent invocations
+        recent: VecDeque<Duration>,
+
+        /// The longest invocation ever
+        max: Duration,
+
+        /// The number of invocations
+        count: u64,
+    }
+
+    impl MechanismTimings {
+        /// The name of the mechanism: its first label, or its type name.
+        ///
+        /// Names are not unique, e.g. several mechanisms of the same type may be profiled.
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        /// The average duration of the most recent invocations.
+        pub fn average(&self) -> Duration {
+            match self.recent.len() {
+                0 => Duration::default(),
+                len => self.recent.iter().sum::<Duration>() / len as u32,
+            }
+        }
+
+        /// The longest duration among the most recent invocations.
+        pub fn recent_max(&self) -> Duration {
+            self.recent.iter().max().cloned().unwrap_or_default()
+        }
+
+        /// The longest duration among all the invocations.
+        pub fn max(&self) -> Duration {
+            self.max
+        }
+
+        /// The number of invocations.
+        pub fn count(&self) -> u64 {
+            self.count
+        }
+
+        /// Records the duration of an invocation
+        fn record(&mut self, duration: Duration, window: usize) {
+            self.recent.push_back(duration);
+            while self.recent.len() > window {
+                self.recent.pop_front();
+            }
+            self.max = self.max.max(duration);
+            self.count += 1;
+        }
+    }
+
+    /// A complete event of the Chrome trace
+    #[derive(Clone, Debug)]
+    struct TraceEvent {
+        /// The id of the mechanism
+        id: usize,
+
+        /// The name of the mechanism
+        name: String,
+
+        /// The handled event
+        event: String,
+
+        /// The sample of the invocation
+        sample: MechanismSample,
+    }
+
+    /// A substate, which collects the timings of the mechanisms.
+    ///
+    /// Mechanisms are profiled by their ids, which are unique within the runtime,
+    /// so the mechanisms, which share the name, are profiled separately.
+    /// Mechanisms are named by their first label, or by their type name,
+    /// if they have no labels, and the names are only used for display.
+    ///
+    /// Profiling is enabled with `ClockworkBuilder::profile_mechanisms`,
+    /// and is only available with the `profiling` feature.
+    #[derive(Debug)]
+    pub struct MechanismProfiles {
+        /// The moment, from which the trace is timed
+        origin: Instant,
+
+        /// The number of recent invocations, which are averaged
+        window: usize,
+
+        /// Timing statistics by mechanism id
+        timings: HashMap<usize, MechanismTimings>,
+
+        /// Whether the trace is being recorded
+        tracing: bool,
+
+        /// The recorded trace
+        trace: Vec<TraceEvent>,
+    }
+    impl ClockworkState for MechanismProfiles {}
+
+    impl Default for MechanismProfiles {
+        fn default() -> Self {
+            Self::new(120)
+        }
+    }
+
+    impl MechanismProfiles {
+        /// Creates empty profiles, which average the timings
+        /// over the given number of recent invocations.
+        pub fn new(window: usize) -> Self {
+            Self {
+                origin: Instant::now(),
+                window,
+                timings: Default::default(),
+                tracing: false,
+                trace: Default::default(),
+            }
+        }
+
+        /// Gets the timing statistics of the mechanism by its id.
+        pub fn get(&self, id: usize) -> Option<&MechanismTimings> {
+            self.timings.get(&id)
+        }
+
+        /// Gets the timing statistics of every mechanism with the name.
+        pub fn get_by_name<'a>(
+            &'a self,
+            name: &'a str,
+        ) -> impl Iterator<Item = (usize, &'a MechanismTimings)> + 'a {
+            self.timings
+                .iter()
+                .filter(move |(_, timings)| timings.name == name)
+                .map(|(&id, timings)| (id, timings))
+        }
+
+        /// Gets the timing statistics of every profiled mechanism by its id.
+        pub fn timings(&self) -> &HashMap<usize, MechanismTimings> {
+            &self.timings
+        }
+
+        /// Starts recording the trace, dropping the previously recorded one.
+        pub fn start_trace(&mut self) {
+            self.trace.clear();
+            self.tracing = true
+        }
+
+        /// Stops recording the trace, keeping the recorded one.
+        pub fn stop_trace(&mut self) {
+            self.tracing = false
+        }
+
+        /// Checks, whether the trace is being recorded.
+        pub fn is_tracing(&self) -> bool {
+            self.tracing
+        }
+
+        /// Exports the recorded trace in the Chrome trace-event JSON format,
+        /// which can be opened in `chrome://tracing`, or in Perfetto.
+        pub fn chrome_trace(&self) -> String {
+            let mut json = String::from("{\"traceEvents\":[");
+            self.trace.iter().enumerate().for_each(|(index, event)| {
+                if index > 0 {
+                    json.push(',');
+                }
+                write!(
+                    json,
+                    "{{\"name\":\"{}\",\"cat\":\"mechanism\",\"ph\":\"X\",\
+                         \"ts\":{:.3},\"dur\":{:.3},\"pid\":0,\"tid\":{},\
+                         \"args\":{{\"event\":\"{}\",\"mechanism\":{}}}}}",
+                    escape(&event.name),
+                    micros(event.sample.start.saturating_duration_since(self.origin)),
+                    micros(event.sample.duration),
+                    event.sample.thread,
+                    escape(&event.event),
+                    event.id,
+                )
+                .expect("Writing into a string never fails")
+            });
+            json.push_str("],\"displayTimeUnit\":\"ms\"}");
+            json
+        }
+
+        /// Records the sample of the mechanism invocation
+        pub(crate) fn record(
+            &mut self,
+            id: usize,
+            name: &str,
+            event: &str,
+            sample: &MechanismSample,
+        ) {
+            let window = self.window;
+            self.timings
+                .entry(id)
+                .or_insert_with(|| MechanismTimings {
+                    name: name.to_owned(),
+                    ..Default::default()
+                })
+                .record(sample.duration, window);
+            if self.tracing {
+                self.trace.push(TraceEvent {
+                    id,
+                    name: name.to_owned(),
+                    event: event.to_owned(),
+                    sample: sample.clone(),
+                })
+            }
+        }
+    }
+
+    /// Converts the duration into fractional microseconds
+    fn micros(duration: Duration) -> f64 {
+        duration.as_secs_f64() * 1e6
+    }
+
+    /// Escapes the string for a JSON string literal
+    fn escape(string: &str) -> String {
+        string
+            .chars()
+            .fold(String::with_capacity(string.len()), |mut escaped, c| {
+                match c {
+                    '"' => escaped.push_str("\\\""),
+                    '\\' => escaped.push_str("\\\\"),
+                    '\n' => escaped.push_str("\\n"),
+                    c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32)
+                        .expect("Writing into a string never fails"),
+                    c => escaped.push(c),
+                }
+                escaped
+            })
+    }
+}
+
+/// A no-op replacement of the profiling, which costs nothing
+#[cfg(not(feature = "profiling"))]
+mod disabled {
+    /// An empty collection of samples
+    #[derive(Default)]
+    pub(crate) struct Samples;
+
+    impl Samples {
+        /// Invokes the mechanism without measuring it
+        #[inline(always)]
+        pub(crate) fn measure<R>(&mut self, _id: usize, clink: impl FnOnce() -> R) -> R {
+            clink()
+        }
+
+        /// Does nothing
+        #[inline(always)]
+        pub(crate) fn append(&mut self, _other: Samples) {}
+    }
+}
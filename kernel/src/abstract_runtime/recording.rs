@@ -0,0 +1,223 @@
+use super::{event::ClockworkEvent, state::ClockworkState};
+use crate::standard_runtime::StandardRuntimeStatistics;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    io::{Read, Write},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// The magic bytes, which start every recording file
+const RECORDING_MAGIC: [u8; 4] = *b"CWRS";
+
+/// The version of the recording file layout.
+///
+/// Recordings of other versions are rejected.
+pub const RECORDING_VERSION: u32 = 1;
+
+/// A function, which passes the event into the `SessionRecording` substate
+pub(crate) type Recorder<S, E> = fn(&mut S, &E);
+
+/// A single entry of the recorded session.
+///
+/// The entries are replayed in the recorded order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SessionEntry<E, I> {
+    /// An event, which has been passed to `Mechanisms::clink_event`
+    Event(E),
+
+    /// Runtime statistics, which have been updated by the main loop
+    Timing(SessionTiming),
+
+    /// A change of the input state
+    Input(I),
+}
+
+/// Runtime statistics at some moment of the session.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionTiming {
+    /// The tick delta
+    pub tick_delta: Duration,
+
+    /// The draw delta
+    pub draw_delta: Duration,
+
+    /// The amount of ticks, passed by now
+    pub ticks_total: u64,
+
+    /// The amount of frames, drawn by now
+    pub frames_total: u64,
+}
+
+impl SessionTiming {
+    /// Takes the timing from the runtime statistics.
+    pub fn of<T>(statistics: &T) -> Self
+    where
+        T: StandardRuntimeStatistics,
+        T::Count: Into<u64>,
+    {
+        Self {
+            tick_delta: statistics.current_tick_delta(),
+            draw_delta: statistics.current_draw_delta(),
+            ticks_total: statistics.ticks_total().into(),
+            frames_total: statistics.frames_total().into(),
+        }
+    }
+}
+
+/// An error, which occurs during the saving, or the loading of a recording.
+#[derive(Error, Debug)]
+pub enum RecordingError {
+    #[error("Failed to access the recording file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to encode, or decode the recording: {0}")]
+    Encoding(String),
+
+    #[error("The file is not a session recording")]
+    NotARecording,
+
+    #[error("Unsupported recording version {found} (expected {expected})")]
+    Version { expected: u32, found: u32 },
+}
+
+/// A substate, which records the session for a later replay.
+///
+/// While the recording is active, every event, passed to `Mechanisms::clink_event`,
+/// is recorded, provided that the recording has been enabled
+/// with `ClockworkBuilder::record_session`.
+/// Main loops record the timings, and the input changes of type `I`.
+///
+/// Nothing is recorded, until `SessionRecording::start` is called.
+#[derive(Debug)]
+pub struct SessionRecording<E, I>
+where
+    E: ClockworkEvent,
+{
+    /// Whether the session is being recorded
+    recording: bool,
+
+    /// The recorded entries
+    entries: Vec<SessionEntry<E, I>>,
+}
+impl<E, I> ClockworkState for SessionRecording<E, I>
+where
+    E: ClockworkEvent,
+    I: 'static,
+{
+}
+
+impl<E, I> Default for SessionRecording<E, I>
+where
+    E: ClockworkEvent,
+{
+    fn default() -> Self {
+        Self {
+            recording: false,
+            entries: Default::default(),
+        }
+    }
+}
+
+impl<E, I> SessionRecording<E, I>
+where
+    E: ClockworkEvent,
+{
+    /// Starts recording the session, dropping the previously recorded one.
+    pub fn start(&mut self) {
+        self.entries.clear();
+        self.recording = true
+    }
+
+    /// Stops recording the session, keeping the recorded one.
+    pub fn stop(&mut self) {
+        self.recording = false
+    }
+
+    /// Checks, whether the session is being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Gets the recorded entries.
+    pub fn entries(&self) -> &[SessionEntry<E, I>] {
+        &self.entries
+    }
+
+    /// Takes the recorded entries, leaving the recording empty.
+    pub fn take(&mut self) -> Vec<SessionEntry<E, I>> {
+        std::mem::take(&mut self.entries)
+    }
+
+    /// Records the event, if the session is being recorded.
+    pub fn record_event(&mut self, event: &E) {
+        self.record(|| SessionEntry::Event(event.clone()))
+    }
+
+    /// Records the timing, if the session is being recorded.
+    pub fn record_timing(&mut self, timing: SessionTiming) {
+        self.record(|| SessionEntry::Timing(timing))
+    }
+
+    /// Records the input change, if the session is being recorded.
+    pub fn record_input(&mut self, input: I) {
+        self.record(|| SessionEntry::Input(input))
+    }
+
+    /// Writes the recorded entries in a compact binary form.
+    pub fn save(&self, writer: impl Write) -> Result<(), RecordingError>
+    where
+        E: Serialize,
+        I: Serialize,
+    {
+        save_session(writer, &self.entries)
+    }
+
+    /// Reads the entries, written by `SessionRecording::save`.
+    pub fn load(reader: impl Read) -> Result<Vec<SessionEntry<E, I>>, RecordingError>
+    where
+        E: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        load_session(reader)
+    }
+
+    /// Pushes the entry, if the session is being recorded
+    fn record(&mut self, entry: impl FnOnce() -> SessionEntry<E, I>) {
+        if self.recording {
+            self.entries.push(entry())
+        }
+    }
+}
+
+/// Writes the header, and the entries
+fn save_session<T>(mut writer: impl Write, entries: &T) -> Result<(), RecordingError>
+where
+    T: Serialize,
+{
+    writer.write_all(&RECORDING_MAGIC)?;
+    bincode::serialize_into(&mut writer, &RECORDING_VERSION)
+        .and_then(|_| bincode::serialize_into(&mut writer, entries))
+        .map_err(|error| RecordingError::Encoding(error.to_string()))?;
+    Ok(writer.flush()?)
+}
+
+/// Checks the header, and reads the entries
+fn load_session<T>(mut reader: impl Read) -> Result<T, RecordingError>
+where
+    T: DeserializeOwned,
+{
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+    if magic != RECORDING_MAGIC {
+        return Err(RecordingError::NotARecording);
+    }
+    let encoding_error = |error: bincode::Error| RecordingError::Encoding(error.to_string());
+    match bincode::deserialize_from(&mut reader).map_err(encoding_error)? {
+        RECORDING_VERSION => bincode::deserialize_from(reader).map_err(encoding_error),
+        found => Err(RecordingError::Version {
+            expected: RECORDING_VERSION,
+            found,
+        }),
+    }
+}
@@ -0,0 +1,334 @@
+use super::state::{ClockworkState, EngineState, Substate};
+use log::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// The version of the archive layout.
+///
+/// Archives of other versions are rejected.
+pub const SNAPSHOT_ARCHIVE_VERSION: u32 = 1;
+
+/// A substate, which participates in the engine state snapshots.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::{abstract_runtime::{ClockworkState, Snapshot}, prelude::*};
+/// #[derive(Serialize, Deserialize)]
+/// struct Score(u32);
+/// impl ClockworkState for Score {}
+/// impl Snapshot for Score {
+///     const KEY: &'static str = "score";
+/// }
+/// ```
+pub trait Snapshot
+where
+    Self: ClockworkState + Serialize + DeserializeOwned,
+{
+    /// A key of the substate in the archive.
+    ///
+    /// Must be unique among the substates of the snapshot.
+    const KEY: &'static str;
+
+    /// A version of the serialized representation of the substate.
+    ///
+    /// Archives, which contain the substate of another version, are rejected.
+    const VERSION: u32 = 1;
+
+    /// Restores the substate in place.
+    ///
+    /// The default implementation replaces the substate with the restored one.
+    /// It might be overridden in order to keep some runtime data,
+    /// which is not a part of the snapshot.
+    fn restore(&mut self, restored: Self) {
+        *self = restored
+    }
+}
+
+/// A format of the snapshot archive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SnapshotFormat {
+    /// A compact binary format (bincode)
+    Binary,
+
+    /// A human-readable text format (RON)
+    Ron,
+}
+
+/// An error, which occurs during the saving, or the restoration of a snapshot.
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    #[error("Failed to encode substate {0}: {1}")]
+    Encoding(String, String),
+
+    #[error("Failed to decode substate {0}: {1}")]
+    Decoding(String, String),
+
+    #[error("Unsupported snapshot archive version {found} (expected {expected})")]
+    ArchiveVersion { expected: u32, found: u32 },
+
+    #[error("Unsupported version {found} of substate {key} (expected {expected})")]
+    SubstateVersion {
+        key: String,
+        expected: u32,
+        found: u32,
+    },
+
+    #[error("Substate {0} is missing in the snapshot archive")]
+    MissingSubstate(String),
+}
+
+/// A set of substates of the state `S`, which participate in the snapshots.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::{abstract_runtime::*, prelude::*};
+/// #[derive(Serialize, Deserialize)]
+/// struct Score(u32);
+/// impl ClockworkState for Score {}
+/// impl Snapshot for Score {
+///     const KEY: &'static str = "score";
+/// }
+///
+/// #[derive(ClockworkState)]
+/// struct State {
+///     score: Score,
+/// }
+///
+/// let snapshots = Snapshots::<State>::default().with::<Score>();
+/// ```
+pub struct Snapshots<S>
+where
+    S: ClockworkState,
+{
+    /// Participating substates
+    substates: Vec<RegisteredSubstate<S>>,
+}
+
+/// A type-erased participating substate
+struct RegisteredSubstate<S> {
+    /// The key of the substate
+    key: &'static str,
+
+    /// The version of the substate
+    version: u32,
+
+    /// Serializes the substate
+    save: fn(&S, SnapshotFormat) -> Result<SubstateData, SnapshotError>,
+
+    /// Deserializes the substate into its restoration
+    load: fn(SubstateData) -> Result<Restoration<S>, SnapshotError>,
+}
+
+/// A deserialized substate, which is ready to be put into the state
+type Restoration<S> = Box<dyn FnOnce(&mut S)>;
+
+/// The root of the snapshot archive
+#[derive(Serialize, Deserialize)]
+struct SnapshotArchive {
+    /// The version of the archive layout
+    version: u32,
+
+    /// Serialized substates by key
+    substates: BTreeMap<String, SubstateEntry>,
+}
+
+/// A serialized substate
+#[derive(Serialize, Deserialize)]
+struct SubstateEntry {
+    /// The version of the substate
+    version: u32,
+
+    /// The serialized substate
+    data: SubstateData,
+}
+
+/// A serialized substate in the format of the archive
+#[derive(Serialize, Deserialize)]
+enum SubstateData {
+    Binary(Vec<u8>),
+    Ron(String),
+}
+
+impl<S> Default for Snapshots<S>
+where
+    S: ClockworkState,
+{
+    fn default() -> Self {
+        Self {
+            substates: Default::default(),
+        }
+    }
+}
+
+impl<S> Snapshots<S>
+where
+    S: ClockworkState,
+{
+    /// Adds the substate to the snapshots.
+    ///
+    /// A previously added substate with the same key is replaced.
+    pub fn with<T>(mut self) -> Self
+    where
+        S: Substate<T>,
+        T: Snapshot,
+    {
+        if self.substates.iter().any(|substate| substate.key == T::KEY) {
+            warn!("Replacing snapshot substate with key {}", T::KEY);
+            self.substates.retain(|substate| substate.key != T::KEY);
+        }
+        self.substates.push(RegisteredSubstate {
+            key: T::KEY,
+            version: T::VERSION,
+            save: |state, format| state.substate(|substate: &T| encode(T::KEY, substate, format)),
+            load: |data| {
+                let restored = decode::<T>(T::KEY, data)?;
+                Ok(Box::new(move |state: &mut S| {
+                    state.substate_mut(|substate: &mut T| substate.restore(restored))
+                }))
+            },
+        });
+        self
+    }
+
+    /// Serializes every participating substate into an archive.
+    pub fn save(&self, state: &S, format: SnapshotFormat) -> Result<Vec<u8>, SnapshotError> {
+        let archive = SnapshotArchive {
+            version: SNAPSHOT_ARCHIVE_VERSION,
+            substates: self
+                .substates
+                .iter()
+                .map(|substate| {
+                    Ok((
+                        substate.key.to_owned(),
+                        SubstateEntry {
+                            version: substate.version,
+                            data: (substate.save)(state, format)?,
+                        },
+                    ))
+                })
+                .collect::<Result<_, SnapshotError>>()?,
+        };
+        match format {
+            SnapshotFormat::Binary => bincode::serialize(&archive)
+                .map_err(|error| SnapshotError::Encoding("archive".into(), error.to_string())),
+            SnapshotFormat::Ron => ron::ser::to_string_pretty(&archive, Default::default())
+                .map(String::into_bytes)
+                .map_err(|error| SnapshotError::Encoding("archive".into(), error.to_string())),
+        }
+    }
+
+    /// Restores every participating substate from the archive.
+    ///
+    /// The restoration is atomic: if some substate fails to be deserialized,
+    /// then the state is left untouched.
+    pub fn restore(
+        &self,
+        state: &mut S,
+        archive: &[u8],
+        format: SnapshotFormat,
+    ) -> Result<(), SnapshotError> {
+        let decoding_error = |error: String| SnapshotError::Decoding("archive".into(), error);
+        let mut archive: SnapshotArchive = match format {
+            SnapshotFormat::Binary => {
+                bincode::deserialize(archive).map_err(|error| decoding_error(error.to_string()))?
+            }
+            SnapshotFormat::Ron => std::str::from_utf8(archive)
+                .map_err(|error| decoding_error(error.to_string()))
+                .and_then(|archive| {
+                    ron::from_str(archive).map_err(|error| decoding_error(error.to_string()))
+                })?,
+        };
+        if archive.version != SNAPSHOT_ARCHIVE_VERSION {
+            return Err(SnapshotError::ArchiveVersion {
+                expected: SNAPSHOT_ARCHIVE_VERSION,
+                found: archive.version,
+            });
+        }
+        let restorations = self
+            .substates
+            .iter()
+            .map(|substate| {
+                let entry = archive
+                    .substates
+                    .remove(substate.key)
+                    .ok_or_else(|| SnapshotError::MissingSubstate(substate.key.to_owned()))?;
+                match entry.version == substate.version {
+                    true => (substate.load)(entry.data),
+                    false => Err(SnapshotError::SubstateVersion {
+                        key: substate.key.to_owned(),
+                        expected: substate.version,
+                        found: entry.version,
+                    }),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        archive
+            .substates
+            .keys()
+            .for_each(|key| warn!("Ignoring unknown snapshot substate {}", key));
+        restorations
+            .into_iter()
+            .for_each(|restoration| restoration(state));
+        Ok(())
+    }
+}
+
+/// Snapshots
+impl<S> EngineState<S>
+where
+    S: ClockworkState,
+{
+    /// Serializes the participating substates into an archive.
+    pub fn save_snapshot(
+        &self,
+        snapshots: &Snapshots<S>,
+        format: SnapshotFormat,
+    ) -> Result<Vec<u8>, SnapshotError> {
+        snapshots.save(&self.0, format)
+    }
+
+    /// Restores the participating substates from the archive.
+    ///
+    /// See `Snapshots::restore` for details.
+    pub fn restore_snapshot(
+        &mut self,
+        snapshots: &Snapshots<S>,
+        archive: &[u8],
+        format: SnapshotFormat,
+    ) -> Result<(), SnapshotError> {
+        snapshots.restore(&mut self.0, archive, format)
+    }
+}
+
+/// Serializes the substate in the format
+fn encode<T>(key: &str, substate: &T, format: SnapshotFormat) -> Result<SubstateData, SnapshotError>
+where
+    T: Serialize,
+{
+    let encoding_error = |error: String| SnapshotError::Encoding(key.to_owned(), error);
+    match format {
+        SnapshotFormat::Binary => bincode::serialize(substate)
+            .map(SubstateData::Binary)
+            .map_err(|error| encoding_error(error.to_string())),
+        SnapshotFormat::Ron => ron::to_string(substate)
+            .map(SubstateData::Ron)
+            .map_err(|error| encoding_error(error.to_string())),
+    }
+}
+
+/// Deserializes the substate from its format
+fn decode<T>(key: &str, data: SubstateData) -> Result<T, SnapshotError>
+where
+    T: DeserializeOwned,
+{
+    let decoding_error = |error: String| SnapshotError::Decoding(key.to_owned(), error);
+    match data {
+        SubstateData::Binary(data) => {
+            bincode::deserialize(&data).map_err(|error| decoding_error(error.to_string()))
+        }
+        SubstateData::Ron(data) => {
+            ron::from_str(&data).map_err(|error| decoding_error(error.to_string()))
+        }
+    }
+}
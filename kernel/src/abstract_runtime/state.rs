@@ -1,3 +1,4 @@
+use super::{commands::RuntimeCommands, disjoint::DisjointSubstatesCallback};
 use ambassador::delegatable_trait;
 
 /// A set of constraints, which every valid Clockwork state should satisfy.
@@ -59,7 +60,10 @@ where
 /// A wrapper struct for the engine state that
 /// allows to access the substate objects through
 /// callback guards.
-pub struct EngineState<S>(pub(crate) S)
+///
+/// Besides the state, it carries a queue of runtime commands,
+/// which mechanisms may issue during their invocation.
+pub struct EngineState<S>(pub(crate) S, pub(crate) RuntimeCommands)
 where
     S: ClockworkState;
 
@@ -85,6 +89,11 @@ impl<S> EngineState<S>
 where
     S: ClockworkState,
 {
+    /// Wraps the state, so that it could be passed to the main loop.
+    pub(crate) fn new(state: S) -> Self {
+        Self(state, Default::default())
+    }
+
     /// Constructs a read callback guard,
     /// allowing read-only access to its substate objects through a callback.
     pub fn start_access(&self) -> ReadCallbackGuard<'_, S, ()> {
@@ -211,6 +220,48 @@ where
             state,
         }
     }
+
+    /// Executes the callback, which takes mutable references to several distinct
+    /// substates at once, returning some result.
+    ///
+    /// The method is only available for WriteCallbackGuards with empty return result.
+    ///
+    /// Example:
+    /// ```
+    /// # use spc_clockwork_kernel::abstract_runtime::*;
+    /// # #[derive(Default)] struct Physics(u32);
+    /// # impl ClockworkState for Physics {}
+    /// # #[derive(Default)] struct Ecs(u32);
+    /// # impl ClockworkState for Ecs {}
+    /// #[derive(ClockworkState, Default)]
+    /// struct State {
+    ///     physics: Physics,
+    ///     ecs: Ecs,
+    /// }
+    ///
+    /// fn synchronize(state: &mut EngineState<State>) {
+    ///     state
+    ///         .start_mutate()
+    ///         .get_disjoint_mut(|physics: &mut Physics, ecs: &mut Ecs| ecs.0 = physics.0)
+    ///         .finish()
+    /// }
+    /// ```
+    ///
+    /// Only the fields of the superstate (see `FieldSubstate`) can be borrowed together,
+    /// so the superstate, and its nested substates are rejected at compile time.
+    ///
+    /// # Panics
+    /// Panics if some substate type repeats.
+    pub fn get_disjoint_mut<T, U>(
+        self,
+        callback: impl DisjointSubstatesCallback<S, T, U>,
+    ) -> WriteCallbackGuard<'a, S, U> {
+        let Self { state, .. } = self;
+        WriteCallbackGuard {
+            result: callback.call_disjoint(&mut state.0),
+            state,
+        }
+    }
 }
 
 impl<'a, S, R> WriteCallbackGuard<'a, S, R>
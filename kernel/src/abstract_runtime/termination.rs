@@ -0,0 +1,126 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    process::{ExitCode, Termination},
+    sync::{Arc, Mutex},
+};
+
+/// The reason of the engine termination.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TerminationReason {
+    /// A mechanism has requested the termination through
+    /// `EngineState::request_termination`
+    Requested {
+        /// The explanation of the request
+        reason: String,
+
+        /// The exit code of the engine
+        exit_code: i32,
+    },
+
+    /// The window of the engine has been closed
+    WindowClosed,
+
+    /// A mechanism with the `ErrorPolicy::Terminate` has failed
+    MechanismFailed(String),
+
+    /// The main loop has returned without a termination request
+    MainLoopReturned,
+}
+
+impl TerminationReason {
+    /// Gets the exit code of the engine, which is terminated for this reason.
+    ///
+    /// Failures of the mechanisms result in 1, other reasons result in 0,
+    /// unless the mechanism has requested a different code.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Requested { exit_code, .. } => *exit_code,
+            Self::MechanismFailed(_) => 1,
+            Self::WindowClosed | Self::MainLoopReturned => 0,
+        }
+    }
+}
+
+impl Display for TerminationReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Requested { reason, .. } => write!(f, "requested: {}", reason),
+            Self::WindowClosed => write!(f, "the window has been closed"),
+            Self::MechanismFailed(name) => write!(f, "mechanism {} has failed", name),
+            Self::MainLoopReturned => write!(f, "the main loop has returned"),
+        }
+    }
+}
+
+/// The outcome of the engine run, which is returned by `Clockwork::set_the_clock`.
+///
+/// The outcome may be returned from `main`, in which case the process
+/// exits with the exit code of the engine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClockworkExit {
+    /// The exit code of the engine
+    pub exit_code: i32,
+
+    /// The reason of the termination
+    pub reason: TerminationReason,
+}
+
+impl ClockworkExit {
+    /// Checks, whether the engine has terminated successfully (i.e. with a zero exit code).
+    pub fn is_success(&self) -> bool {
+        self.exit_code == 0
+    }
+}
+
+impl From<TerminationReason> for ClockworkExit {
+    fn from(reason: TerminationReason) -> Self {
+        Self {
+            exit_code: reason.exit_code(),
+            reason,
+        }
+    }
+}
+
+impl Termination for ClockworkExit {
+    fn report(self) -> ExitCode {
+        match u8::try_from(self.exit_code) {
+            Ok(exit_code) => ExitCode::from(exit_code),
+            Err(_) => ExitCode::FAILURE,
+        }
+    }
+}
+
+/// The termination request, which is shared between the `Mechanisms`,
+/// and the `Clockwork`, which has passed them to the main loop.
+///
+/// Only the first request is kept.
+#[derive(Clone, Default)]
+pub(crate) struct TerminationRequest(Arc<Mutex<Option<TerminationReason>>>);
+
+impl TerminationRequest {
+    /// Stores the reason, unless the termination has already been requested.
+    ///
+    /// Returns false, if the termination has already been requested.
+    pub(crate) fn request(&self, reason: TerminationReason) -> bool {
+        let mut request = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match *request {
+            Some(_) => false,
+            None => {
+                *request = Some(reason);
+                true
+            }
+        }
+    }
+
+    /// Gets the reason of the requested termination.
+    pub(crate) fn reason(&self) -> Option<TerminationReason> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
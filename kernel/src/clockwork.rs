@@ -1,16 +1,22 @@
+#[cfg(feature = "profiling")]
+use crate::abstract_runtime::MechanismProfiles;
 use crate::{
     abstract_runtime::{
-        ClockworkEvent, ClockworkState, EngineState, MainLoop, Mechanism, Mechanisms,
+        ClockworkEvent, ClockworkExit, ClockworkState, ClosureMechanism, EngineState, ErrorPolicy,
+        FallibleMechanism, MainLoop, Mechanism, MechanismLabel, MechanismOrdering, Mechanisms,
+        ParallelMechanism, SessionRecording, Substate, TerminationReason,
     },
+    plugin::Plugin,
     standard_runtime::{
-        StandardEvent, StandardEventSuperset, StandardMechanism, StandardMechanismWrapper,
+        GameState, GameStateMachine, StandardEvent, StandardEventSuperset, StandardMechanism,
+        StandardMechanismWrapper,
     },
-    util::{derive_builder::Builder, log::*},
+    util::log::*,
 };
+use std::fmt::Debug;
+use thiserror::Error;
 
 /// `Clockwork` is a type, which represents the game engine.
-#[derive(Builder)]
-#[builder(pattern = "owned", setter(into))]
 pub struct Clockwork<S, E = StandardEvent>
 where
     S: ClockworkState,
@@ -20,7 +26,6 @@ where
     ///
     /// It is responsible for manipulating the shared state of the application
     /// through a repetitive event emission, and the invocation of the mechanisms.
-    #[builder(private, setter(name = "__main_loop", into = "false"))]
     main_loop: Box<dyn MainLoop<S, E>>,
 
     /// The Clockwork state, which is shared between mechanisms at runtime.
@@ -30,10 +35,67 @@ where
     ///
     /// This data structure (at Clockwork initialization stored as builder)
     /// is storing and invoking mechanisms by event.
-    #[builder(private, setter(name = "__mechanisms", into = "false"))]
     mechanisms: Mechanisms<S, E>,
 }
 
+/// A builder of the `Clockwork`.
+///
+/// The builder is not derived, since it also keeps the plugins,
+/// which are installed by `ClockworkBuilder::build`, and are not a part of the Clockwork.
+pub struct ClockworkBuilder<S, E = StandardEvent>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// The main loop of the Clockwork runtime
+    main_loop: Option<Box<dyn MainLoop<S, E>>>,
+
+    /// The Clockwork state
+    state: Option<S>,
+
+    /// A system of mechanisms
+    mechanisms: Option<Mechanisms<S, E>>,
+
+    /// Plugins, which are waiting to be installed
+    plugins: Vec<PluginInstaller<S, E>>,
+}
+
+impl<S, E> Default for ClockworkBuilder<S, E>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    fn default() -> Self {
+        Self {
+            main_loop: None,
+            state: None,
+            mechanisms: None,
+            plugins: Default::default(),
+        }
+    }
+}
+
+/// An error of the Clockwork assembly.
+#[derive(Error, Debug)]
+pub enum ClockworkBuilderError {
+    #[error("`{0}` must be initialized")]
+    UninitializedField(&'static str),
+
+    #[error("{0}")]
+    ValidationError(String),
+}
+
+impl From<String> for ClockworkBuilderError {
+    fn from(error: String) -> Self {
+        Self::ValidationError(error)
+    }
+}
+
+/// A type-erased plugin, which validates, and installs itself into the builder
+type PluginInstaller<S, E> = Box<
+    dyn FnOnce(ClockworkBuilder<S, E>) -> Result<ClockworkBuilder<S, E>, ClockworkBuilderError>,
+>;
+
 impl<S, E> ClockworkBuilder<S, E>
 where
     S: ClockworkState,
@@ -43,15 +105,186 @@ where
     ///
     /// It is responsible for manipulating the shared state of the application
     /// through a repetitive event emission, and the invocation of the mechanisms.
-    pub fn main_loop(self, loop_fn: impl MainLoop<S, E> + 'static) -> Self {
-        self.__main_loop(Box::new(loop_fn))
+    pub fn main_loop(mut self, loop_fn: impl MainLoop<S, E> + 'static) -> Self {
+        self.main_loop = Some(Box::new(loop_fn));
+        self
+    }
+
+    /// The Clockwork state, which is shared between mechanisms at runtime.
+    pub fn state(mut self, state: impl Into<S>) -> Self {
+        self.state = Some(state.into());
+        self
     }
 
     /// Adds mechanism to the engine.
-    pub fn add_mechanism(mut self, mechanism: impl Mechanism<S, E> + 'static) -> Self {
+    pub fn add_mechanism(self, mechanism: impl Mechanism<S, E> + 'static) -> Self {
+        self.add_ordered_mechanism(mechanism, Default::default())
+    }
+
+    /// Adds mechanism to the engine, together with its ordering constraints.
+    ///
+    /// The order of the mechanisms is resolved in `ClockworkBuilder::build`.
+    pub fn add_ordered_mechanism(
+        mut self,
+        mechanism: impl Mechanism<S, E> + 'static,
+        ordering: MechanismOrdering,
+    ) -> Self {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .add_mechanism(mechanism, ordering);
+        self
+    }
+
+    /// Adds a closure, which is called upon every event of the same kind as the given one.
+    ///
+    /// Example: `Clockwork::builder().on(StandardEvent::Tick, |state| ...)`
+    pub fn on(
+        self,
+        event: impl Into<E>,
+        mut closure: impl FnMut(&mut EngineState<S>) + 'static,
+    ) -> Self {
+        self.on_event(event.into().kind(), move |state, _| closure(state))
+    }
+
+    /// Adds a closure, which is called upon every event of the given kind,
+    /// receiving the event itself.
+    pub fn on_event(
+        self,
+        kind: E::Kind,
+        closure: impl FnMut(&mut EngineState<S>, E) + 'static,
+    ) -> Self {
+        self.add_mechanism(ClosureMechanism::new(Some(vec![kind]), closure))
+    }
+
+    /// Adds a plugin to the engine.
+    ///
+    /// The plugin is validated, and installed in `ClockworkBuilder::build`.
+    pub fn add_plugin(mut self, plugin: impl Plugin<S, E> + 'static) -> Self {
+        self.plugins.push(Box::new(|builder| {
+            let name = plugin.name();
+            plugin.validate().map_err(|error| {
+                ClockworkBuilderError::ValidationError(format!(
+                    "Invalid configuration of plugin {}: {}",
+                    name, error
+                ))
+            })?;
+            debug!("Installing plugin {}", name);
+            plugin.install(builder)
+        }));
+        self
+    }
+
+    /// Adds fallible mechanism to the engine.
+    ///
+    /// Its failures are handled according to its `ErrorPolicy`.
+    pub fn add_fallible_mechanism(self, mechanism: impl FallibleMechanism<S, E> + 'static) -> Self {
+        self.add_ordered_fallible_mechanism(mechanism, Default::default())
+    }
+
+    /// Adds fallible mechanism to the engine, together with its ordering constraints.
+    ///
+    /// The order of the mechanisms is resolved in `ClockworkBuilder::build`.
+    pub fn add_ordered_fallible_mechanism(
+        mut self,
+        mechanism: impl FallibleMechanism<S, E> + 'static,
+        ordering: MechanismOrdering,
+    ) -> Self {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .add_fallible_mechanism(mechanism, ordering);
+        self
+    }
+
+    /// Adds parallel mechanism to the engine.
+    ///
+    /// Parallel mechanisms, whose substate accesses do not conflict,
+    /// are clinked simultaneously, if they are adjacent in the mechanism order.
+    pub fn add_parallel_mechanism(self, mechanism: impl ParallelMechanism<S, E> + 'static) -> Self
+    where
+        S: Send + Sync,
+    {
+        self.add_ordered_parallel_mechanism(mechanism, Default::default())
+    }
+
+    /// Adds parallel mechanism to the engine, together with its ordering constraints.
+    ///
+    /// The order of the mechanisms is resolved in `ClockworkBuilder::build`.
+    pub fn add_ordered_parallel_mechanism(
+        mut self,
+        mechanism: impl ParallelMechanism<S, E> + 'static,
+        ordering: MechanismOrdering,
+    ) -> Self
+    where
+        S: Send + Sync,
+    {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .add_parallel_mechanism(mechanism, ordering);
+        self
+    }
+
+    /// Sets the maximum depth of follow-up events, emitted by the mechanisms.
+    ///
+    /// Follow-up events, which are emitted deeper than that, are dropped.
+    pub fn max_cascade_depth(mut self, depth: usize) -> Self {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .set_max_cascade_depth(depth);
+        self
+    }
+
+    /// Sets the reaction of the runtime on the failures, and panics
+    /// of the mechanisms with the label.
+    ///
+    /// If a mechanism has several labels with policies,
+    /// the policy of its first such label is applied.
+    pub fn error_policy(mut self, label: impl Into<MechanismLabel>, policy: ErrorPolicy) -> Self {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .set_error_policy(label.into(), policy);
+        self
+    }
+
+    /// Sets the reaction of the runtime on the failures, and panics
+    /// of the mechanisms, which have no labeled error policy.
+    ///
+    /// By default, the failures are logged.
+    pub fn default_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .set_default_error_policy(policy);
+        self
+    }
+
+    /// Enables the recording of the events into the `SessionRecording` substate.
+    ///
+    /// Events are only recorded, while `SessionRecording` is started.
+    /// The type `I` is the type of the input changes, recorded by the main loop.
+    pub fn record_session<I>(mut self) -> Self
+    where
+        S: Substate<SessionRecording<E, I>>,
+        I: 'static,
+    {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .enable_recording::<I>();
+        self
+    }
+
+    /// Enables the profiling of the mechanisms.
+    ///
+    /// The wall-clock time of every mechanism invocation is recorded
+    /// into the `MechanismProfiles` substate of the state.
+    ///
+    /// This method is only available with the `profiling` feature.
+    #[cfg(feature = "profiling")]
+    pub fn profile_mechanisms(mut self) -> Self
+    where
+        S: Substate<MechanismProfiles>,
+    {
         self.mechanisms
             .get_or_insert(Default::default())
-            .add_mechanism(mechanism);
+            .enable_profiling();
         self
     }
 
@@ -65,6 +298,70 @@ where
     {
         self.add_mechanism(StandardMechanismWrapper::from(mechanism))
     }
+
+    /// Converts BaseEventMechanism into the instance of Mechanism,
+    /// then adds this mechanism to the engine, together with its ordering constraints.
+    ///
+    /// This method is only available for Clockwork, whose events are convertible to BaseEvents.
+    pub fn add_ordered_standard_mechanism(
+        self,
+        mechanism: impl StandardMechanism<S> + 'static,
+        ordering: MechanismOrdering,
+    ) -> Self
+    where
+        E: StandardEventSuperset,
+    {
+        self.add_ordered_mechanism(StandardMechanismWrapper::from(mechanism), ordering)
+    }
+
+    /// Adds the state machine of the `GameState<G>` substate to the engine.
+    ///
+    /// The machine is run after every event, once all the mechanisms have been clinked,
+    /// so the transitions, requested during the event, are applied before the next one.
+    ///
+    /// This method is only available for Clockwork, whose events are convertible to BaseEvents.
+    pub fn add_game_state_machine<G>(mut self, mut machine: GameStateMachine<S, G>) -> Self
+    where
+        S: Substate<GameState<G>>,
+        E: StandardEventSuperset,
+        G: Clone + PartialEq + Debug + 'static,
+    {
+        self.mechanisms
+            .get_or_insert(Default::default())
+            .add_after_event_hook(move |state, event| machine.after_event(state, event));
+        self
+    }
+
+    /// Builds the Clockwork, resolving the order of its mechanisms.
+    ///
+    /// # Errors
+    /// Fails, if some of the required fields are missing,
+    /// if the configuration of some plugin is invalid, if some plugin fails to install,
+    /// or if the mechanism ordering constraints form a cycle.
+    pub fn build(mut self) -> Result<Clockwork<S, E>, ClockworkBuilderError> {
+        while !self.plugins.is_empty() {
+            for install in std::mem::take(&mut self.plugins) {
+                self = install(self)?;
+            }
+        }
+        let Self {
+            main_loop,
+            state,
+            mechanisms,
+            ..
+        } = self;
+        let main_loop = main_loop.ok_or(ClockworkBuilderError::UninitializedField("main_loop"))?;
+        let state = state.ok_or(ClockworkBuilderError::UninitializedField("state"))?;
+        let mut mechanisms = mechanisms.unwrap_or_default();
+        mechanisms
+            .resolve_order()
+            .map_err(|error| ClockworkBuilderError::ValidationError(error.to_string()))?;
+        Ok(Clockwork {
+            main_loop,
+            state,
+            mechanisms,
+        })
+    }
 }
 
 impl<S, E> Clockwork<S, E>
@@ -73,15 +370,29 @@ where
     E: ClockworkEvent,
 {
     /// Initializes the game engine, and starts the main loop.
-    pub fn set_the_clock(self) {
+    ///
+    /// Returns the exit code, and the reason of the termination,
+    /// once the main loop returns. If the main loop returns without
+    /// a termination request, the reason is `TerminationReason::MainLoopReturned`.
+    pub fn set_the_clock(self) -> ClockworkExit {
         let Self {
             main_loop,
             state,
             mechanisms,
         } = self;
+        let termination = mechanisms.termination_request();
         info!("Starting Clockwork Engine");
-        main_loop(EngineState(state), mechanisms);
-        info!("Terminating Clockwork Engine");
+        main_loop(EngineState::new(state), mechanisms);
+        let exit = ClockworkExit::from(
+            termination
+                .reason()
+                .unwrap_or(TerminationReason::MainLoopReturned),
+        );
+        info!(
+            "Terminating Clockwork Engine with exit code {}: {}",
+            exit.exit_code, exit.reason
+        );
+        exit
     }
 
     /// Creates a new builder struct, which is used for the Clockwork assembly.
@@ -1,4 +1,4 @@
-use crate::math::Vec3;
+use crate::math::{Vec3, Vec4};
 
 #[ambassador::delegatable_trait]
 pub trait Color {
@@ -42,7 +42,7 @@ where
 {
     fn direction(&self) -> Vec3 {
         let [x, y, z, ..]: [f32; 4] =
-            (self.normal_matrix() * [0f32, 0f32, -1f32, 0f32].into()).into();
+            (self.normal_matrix() * Vec4::from([0f32, 0f32, -1f32, 0f32])).into();
         [x, y, z].into()
     }
 }
@@ -20,6 +20,24 @@ pub trait SceneObject {
     fn normal_matrix(&self) -> Mat4;
 }
 
+/// Every object with a `WorldTransform` is a `SceneObject`
+impl<T> SceneObject for T
+where
+    T: WorldTransform,
+{
+    fn world_matrix(&self) -> Mat4 {
+        self.world_transform().to_matrix()
+    }
+
+    fn view_matrix(&self) -> Mat4 {
+        self.world_transform().inverse_matrix()
+    }
+
+    fn normal_matrix(&self) -> Mat4 {
+        self.world_transform().normal_matrix()
+    }
+}
+
 /// A subtype of `SceneObject`, which represents
 /// a Camera, and contains a projection matrix.
 #[ambassador::delegatable_trait]
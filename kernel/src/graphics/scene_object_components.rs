@@ -1,4 +1,4 @@
-use crate::math::Mat4;
+use crate::math::{Mat4, Transform};
 
 /// A component of the camera, which holds a projection matrix
 #[ambassador::delegatable_trait]
@@ -9,3 +9,13 @@ pub trait ProjectionMatrix {
     /// a 2D plane.
     fn projection_matrix(&self) -> Mat4;
 }
+
+/// A component of the scene object, which holds its transform.
+///
+/// Every `WorldTransform` is a `SceneObject`, whose matrices
+/// are derived from the transform.
+#[ambassador::delegatable_trait]
+pub trait WorldTransform {
+    /// Gets the transform from the local space of the object to the world space.
+    fn world_transform(&self) -> Transform;
+}
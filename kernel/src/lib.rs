@@ -1,6 +1,10 @@
+/// Allows the derive macros to refer to the kernel from inside the kernel.
+extern crate self as spc_clockwork_kernel;
+
 /* ---- PRELUDE ---- */
 pub mod prelude {
     pub use crate::clockwork::Clockwork;
+    pub use crate::plugin::Plugin;
     pub use crate::standard_runtime::StandardEvent;
     pub use crate::util::itertools::*;
     pub use crate::util::log::*;
@@ -12,39 +16,90 @@ pub mod prelude {
 /// required for every clockwork operation.
 pub mod abstract_runtime {
     /* ---- PRIVATE ---- */
+    /// Closure mechanisms, and mechanism combinators.
+    mod combinators;
+    /// Runtime commands, issued by the mechanisms.
+    mod commands;
+    /// Simultaneous mutable access to several substates.
+    mod disjoint;
+    /// A state of the dynamic substates, keyed by their types.
+    mod dynamic;
     /// Abstract Clockwork Event definitions.
     mod event;
+    /// Fallible mechanisms and error policies.
+    mod fallible;
     /// Abstract Clockwork main loop definition.
     pub mod main_loop;
     /// Abstract Clockwork Mechanism definitions.
     mod mechanism;
+    /// Mechanism ordering constraints.
+    mod ordering;
+    /// Parallel mechanisms with declared substate access.
+    mod parallel;
+    /// Optional per-mechanism timing.
+    mod profiling;
+    /// Session recording for a later replay.
+    mod recording;
+    /// Engine state snapshots.
+    mod snapshot;
     /// Abstract Clockwork State definitions.
     mod state;
+    /// Termination requests, and the exit status of the engine.
+    mod termination;
 
     /* ---- PUBLIC ---- */
     pub use ambassador::*;
+    pub use combinators::{ClosureMechanism, Filtered, Mapped, MechanismExt, Throttled};
+    pub use disjoint::{DisjointSubstatesCallback, FieldSubstate};
+    pub use dynamic::{DynamicState, DynamicSubstate, MissingSubstateError};
     pub use event::*;
+    pub use fallible::{ErrorPolicy, FallibleMechanism, MechanismError, MechanismPanic};
+    pub use kernel_derive::ClockworkState;
     pub use main_loop::*;
     pub use mechanism::*;
+    pub use ordering::{MechanismLabel, MechanismOrdering, MechanismOrderingError};
+    pub use parallel::{ParallelMechanism, SharedEngineState, SubstateAccess};
+    #[cfg(feature = "profiling")]
+    pub use profiling::{MechanismProfiles, MechanismTimings};
+    pub use recording::{
+        RecordingError, SessionEntry, SessionRecording, SessionTiming, RECORDING_VERSION,
+    };
+    pub use snapshot::{
+        Snapshot, SnapshotError, SnapshotFormat, Snapshots, SNAPSHOT_ARCHIVE_VERSION,
+    };
     pub use state::*;
+    pub use termination::{ClockworkExit, TerminationReason};
 }
 
 /// Clockwork object definitions.
 pub mod clockwork;
 
+/// Plugins, which bundle the mechanisms of a feature.
+pub mod plugin;
+
 /// A set of utilities for a standard Clockwork runtime.
 pub mod standard_runtime {
     /* ---- PRIVATE ---- */
+    /// Typed message channels between the mechanisms.
+    mod channels;
     /// Standard Clockwork Event definitions.
     mod event;
+    /// Game state machine.
+    mod game_state;
     /// Standard mechanism definitions.
     mod mechanism;
+    /// Delayed, and periodic events.
+    mod scheduler;
     /// Standard statistics trait.
     mod statistics;
 
     /* ---- PUBLIC ---- */
+    pub use channels::{EventReader, Events, EventsMechanism};
     pub use event::*;
+    pub use game_state::{GameState, GameStateMachine, GameStateMechanismExt, InGameStates};
+    pub use kernel_derive::standard_mechanism;
     pub use mechanism::*;
+    pub use scheduler::*;
     pub use statistics::*;
 }
 
@@ -83,10 +138,16 @@ pub mod graphics {
 /// > This module may eventually become a separate crate
 pub mod math {
     /* ---- PRIVATE ---- */
+    mod linear_algebra;
+    mod mat4;
     mod matrix;
+    mod quat;
+    mod transform;
 
     /* ---- PUBLIC ---- */
     pub use matrix::Matrix;
+    pub use quat::Quat;
+    pub use transform::Transform;
     pub type Vector<const N: usize> = Matrix<f32, N, 1>;
     pub type Mat2 = Matrix<f32, 2, 2>;
     pub type Mat3 = Matrix<f32, 3, 3>;
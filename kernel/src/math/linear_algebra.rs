@@ -0,0 +1,137 @@
+use super::matrix::Matrix;
+use itertools::iproduct;
+use std::ops::{Div, Mul};
+
+/// Square matrices of floats support the identity, the determinant, and the inverse
+impl<const N: usize> Matrix<f32, N, N> {
+    /// Creates the identity matrix.
+    pub fn identity() -> Self {
+        let mut out = Self::default();
+        (0..N).for_each(|i| out[(i, i)] = 1.0);
+        out
+    }
+
+    /// Computes the determinant of the matrix.
+    pub fn determinant(&self) -> f32 {
+        let mut rows = *self;
+        let mut determinant = 1.0;
+        for pivot in 0..N {
+            let max_row = (pivot..N)
+                .max_by(|&a, &b| rows[(a, pivot)].abs().total_cmp(&rows[(b, pivot)].abs()))
+                .unwrap_or(pivot);
+            if rows[(max_row, pivot)] == 0.0 {
+                return 0.0;
+            }
+            if max_row != pivot {
+                rows.as_mut().swap(max_row, pivot);
+                determinant = -determinant;
+            }
+            determinant *= rows[(pivot, pivot)];
+            for row in pivot + 1..N {
+                let factor = rows[(row, pivot)] / rows[(pivot, pivot)];
+                (pivot..N).for_each(|column| rows[(row, column)] -= factor * rows[(pivot, column)]);
+            }
+        }
+        determinant
+    }
+
+    /// Computes the inverse matrix with the Gauss-Jordan elimination.
+    ///
+    /// Returns `None`, if the matrix is singular (up to the float precision).
+    pub fn inverse(&self) -> Option<Self> {
+        let tolerance = f32::EPSILON
+            * iproduct!(0..N, 0..N)
+                .map(|index| self[index].abs())
+                .fold(0.0, f32::max);
+        let mut rows = *self;
+        let mut out = Self::identity();
+        for pivot in 0..N {
+            let max_row = (pivot..N)
+                .max_by(|&a, &b| rows[(a, pivot)].abs().total_cmp(&rows[(b, pivot)].abs()))
+                .unwrap_or(pivot);
+            if rows[(max_row, pivot)].abs() <= tolerance {
+                return None;
+            }
+            rows.as_mut().swap(max_row, pivot);
+            out.as_mut().swap(max_row, pivot);
+
+            let scale = rows[(pivot, pivot)];
+            for column in 0..N {
+                rows[(pivot, column)] /= scale;
+                out[(pivot, column)] /= scale;
+            }
+            for row in (0..N).filter(|&row| row != pivot) {
+                let factor = rows[(row, pivot)];
+                for column in 0..N {
+                    rows[(row, column)] -= factor * rows[(pivot, column)];
+                    out[(row, column)] -= factor * out[(pivot, column)];
+                }
+            }
+        }
+        Some(out)
+    }
+}
+
+/// Matrices of floats (most notably, vectors) support inner products, and norms
+impl<const N: usize, const M: usize> Matrix<f32, N, M> {
+    /// Computes the inner product of the matrices, i.e. the sum of the element-wise products.
+    ///
+    /// For vectors, this is the dot product.
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        iproduct!(0..N, 0..M)
+            .map(|index| self[index] * rhs[index])
+            .sum()
+    }
+
+    /// Computes the squared euclidean (Frobenius) norm.
+    pub fn norm_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Computes the euclidean (Frobenius) norm.
+    pub fn norm(&self) -> f32 {
+        self.norm_squared().sqrt()
+    }
+
+    /// Gets the matrix, divided by its norm.
+    ///
+    /// The elements of the normalized zero matrix are NaN.
+    pub fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+}
+
+/// 3D vectors support cross products
+impl Matrix<f32, 3, 1> {
+    /// Computes the cross product of the vectors.
+    pub fn cross(&self, rhs: &Self) -> Self {
+        [
+            self[1] * rhs[2] - self[2] * rhs[1],
+            self[2] * rhs[0] - self[0] * rhs[2],
+            self[0] * rhs[1] - self[1] * rhs[0],
+        ]
+        .into()
+    }
+}
+
+/// Matrices of floats can be multiplied by a scalar
+impl<const N: usize, const M: usize> Mul<f32> for Matrix<f32, N, M> {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Into::<[[f32; M]; N]>::into(self)
+            .map(|row| row.map(|element| element * rhs))
+            .into()
+    }
+}
+
+/// Matrices of floats can be divided by a scalar
+impl<const N: usize, const M: usize> Div<f32> for Matrix<f32, N, M> {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Into::<[[f32; M]; N]>::into(self)
+            .map(|row| row.map(|element| element / rhs))
+            .into()
+    }
+}
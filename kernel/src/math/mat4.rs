@@ -0,0 +1,101 @@
+use super::{Mat4, Vec3};
+
+/// Homogeneous transformations, and projections.
+///
+/// The projections follow the OpenGL conventions: the view space is right-handed,
+/// the camera looks towards the negative Z axis, and the depth is mapped to `[-1; 1]`.
+impl Mat4 {
+    /// Creates the translation by the vector.
+    pub fn translation(translation: Vec3) -> Self {
+        let mut out = Self::identity();
+        (0..3).for_each(|i| out[(i, 3)] = translation[i]);
+        out
+    }
+
+    /// Creates the counter-clockwise rotation around the axis by the angle in radians.
+    ///
+    /// The axis is normalized, so it only has to be non-zero.
+    pub fn rotation(axis: Vec3, angle_rad: f32) -> Self {
+        let [x, y, z]: [f32; 3] = axis.normalize().into();
+        let (sin, cos) = angle_rad.sin_cos();
+        let versine = 1.0 - cos;
+        [
+            [
+                cos + x * x * versine,
+                x * y * versine - z * sin,
+                x * z * versine + y * sin,
+                0.0,
+            ],
+            [
+                y * x * versine + z * sin,
+                cos + y * y * versine,
+                y * z * versine - x * sin,
+                0.0,
+            ],
+            [
+                z * x * versine - y * sin,
+                z * y * versine + x * sin,
+                cos + z * z * versine,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ]
+        .into()
+    }
+
+    /// Creates the non-uniform scaling along the axes.
+    pub fn scale(scale: Vec3) -> Self {
+        let mut out = Self::identity();
+        (0..3).for_each(|i| out[(i, i)] = scale[i]);
+        out
+    }
+
+    /// Creates the view matrix of the camera at the eye, which looks at the target.
+    ///
+    /// The up vector must not be parallel to the view direction.
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalize();
+        let right = forward.cross(&up).normalize();
+        let up = right.cross(&forward);
+        [
+            [right[0], right[1], right[2], -right.dot(&eye)],
+            [up[0], up[1], up[2], -up.dot(&eye)],
+            [-forward[0], -forward[1], -forward[2], forward.dot(&eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]
+        .into()
+    }
+
+    /// Creates the perspective projection,
+    /// given the aspect ratio (width / height), and the vertical field of view in radians.
+    pub fn perspective(aspect: f32, fovy_rad: f32, znear: f32, zfar: f32) -> Self {
+        let focal = 1.0 / (fovy_rad / 2.0).tan();
+        let depth = znear - zfar;
+        [
+            [focal / aspect, 0.0, 0.0, 0.0],
+            [0.0, focal, 0.0, 0.0],
+            [0.0, 0.0, (zfar + znear) / depth, 2.0 * zfar * znear / depth],
+            [0.0, 0.0, -1.0, 0.0],
+        ]
+        .into()
+    }
+
+    /// Creates the orthographic projection of the box.
+    pub fn orthographic(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        znear: f32,
+        zfar: f32,
+    ) -> Self {
+        let (width, height, depth) = (right - left, top - bottom, zfar - znear);
+        [
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, -2.0 / depth, -(zfar + znear) / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ]
+        .into()
+    }
+}
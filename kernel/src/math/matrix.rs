@@ -1,6 +1,7 @@
 use itertools::iproduct;
 use std::{
     borrow::{Borrow, BorrowMut},
+    fmt::{self, Debug},
     mem::MaybeUninit,
     ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
 };
@@ -8,6 +9,11 @@ use std::{
 /// A statically sized 2D array, whose elements are allocated on the stack.
 ///
 /// This structure is used primarily for linear algebra.
+/// The matrix has `N` rows and `M` columns, and is indexed by `(row, column)`.
+/// Vectors are columns, so the transformations are applied as `matrix * vector`.
+///
+/// Note, that the 2D array of the matrix is row-major, while the shaders
+/// expect column-major matrices, so the matrices are transposed before the upload.
 pub struct Matrix<T, const N: usize, const M: usize>([[T; M]; N]);
 
 /// A 2D array can be converted to a matrix
@@ -166,6 +172,43 @@ where
     }
 }
 
+/// Matrices with comparable elements are compared element-wise
+impl<T, const N: usize, const M: usize> PartialEq for Matrix<T, N, M>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// A matrix is formatted as its 2D array
+impl<T, const N: usize, const M: usize> Debug for Matrix<T, N, M>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Matrix").field(&self.0).finish()
+    }
+}
+
+/// Any matrix can be transposed
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Clone,
+{
+    /// Gets the transposed matrix, i.e. the matrix, whose rows are the columns of this one.
+    pub fn transpose(&self) -> Matrix<T, M, N> {
+        let mut out = [[MaybeUninit::<()>::uninit(); N]; M]
+            .map(|row| row.map(|_| MaybeUninit::<T>::uninit()));
+        for (i, j) in iproduct!(0..N, 0..M) {
+            out[j][i].write(self[(i, j)].clone());
+        }
+        out.map(|row| row.map(|element| unsafe { element.assume_init() }))
+            .into()
+    }
+}
+
 /// A Matrix is indexed by a pair of unsigned numbers
 impl<T, const N: usize, const M: usize> Index<(usize, usize)> for Matrix<T, N, M> {
     type Output = T;
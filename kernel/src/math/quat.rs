@@ -0,0 +1,174 @@
+use super::{Mat4, Vec3, Vec4};
+use std::ops::Mul;
+
+/// A quaternion `w + xi + yj + zk`, which is used to represent 3D rotations.
+///
+/// The rotations are expected to be unit quaternions; the constructors
+/// produce unit quaternions, and the interpolations keep them normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    /// Creates the quaternion from its coordinates.
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates the identity rotation.
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates the counter-clockwise rotation around the axis by the angle in radians.
+    ///
+    /// The axis is normalized, so it only has to be non-zero.
+    pub fn from_axis_angle(axis: Vec3, angle_rad: f32) -> Self {
+        let (sin, cos) = (angle_rad / 2.0).sin_cos();
+        let [x, y, z]: [f32; 3] = (axis.normalize() * sin).into();
+        Self::new(x, y, z, cos)
+    }
+
+    /// Gets the vector part of the quaternion.
+    pub fn vector(&self) -> Vec3 {
+        [self.x, self.y, self.z].into()
+    }
+
+    /// Computes the dot product of the quaternions as 4D vectors.
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        Vec4::from(*self).dot(&Vec4::from(*rhs))
+    }
+
+    /// Computes the norm of the quaternion.
+    pub fn norm(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Gets the unit quaternion of the same direction.
+    pub fn normalize(&self) -> Self {
+        Vec4::from(*self).normalize().into()
+    }
+
+    /// Gets the conjugate quaternion, which is the inverse rotation for a unit quaternion.
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Gets the inverse quaternion.
+    pub fn inverse(&self) -> Self {
+        (Vec4::from(self.conjugate()) / self.dot(self)).into()
+    }
+
+    /// Rotates the vector.
+    pub fn rotate(&self, vector: Vec3) -> Vec3 {
+        let doubled_cross = self.vector().cross(&vector) * 2.0;
+        vector + doubled_cross * self.w + self.vector().cross(&doubled_cross)
+    }
+
+    /// Interpolates the rotations linearly, and normalizes the result (i.e. nlerp).
+    ///
+    /// The interpolation takes the shortest path. It is cheaper than `slerp`,
+    /// but its angular velocity is not constant.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let other = self.shortest(other);
+        (Vec4::from(*self) * (1.0 - t) + Vec4::from(other) * t)
+            .normalize()
+            .into()
+    }
+
+    /// Interpolates the rotations spherically with a constant angular velocity (i.e. slerp).
+    ///
+    /// The interpolation takes the shortest path.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let other = self.shortest(other);
+        let cos = self.dot(&other).min(1.0);
+        if cos > 1.0 - f32::EPSILON.sqrt() {
+            return self.lerp(&other, t);
+        }
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let (from, to) = (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin);
+        (Vec4::from(*self) * from + Vec4::from(other) * to).into()
+    }
+
+    /// Gets the homogeneous rotation matrix.
+    pub fn to_matrix(&self) -> Mat4 {
+        let Self { x, y, z, w } = self.normalize();
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - z * w),
+                2.0 * (x * z + y * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * y + z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - x * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * z - y * w),
+                2.0 * (y * z + x * w),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ]
+        .into()
+    }
+
+    /// Gets the other quaternion, or its negation, whichever is closer to this one.
+    ///
+    /// Both represent the same rotation.
+    fn shortest(&self, other: &Self) -> Self {
+        match self.dot(other) < 0.0 {
+            true => (Vec4::from(*other) * -1.0).into(),
+            false => *other,
+        }
+    }
+}
+
+/// The default rotation is the identity
+impl Default for Quat {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Rotations are composed by the multiplication: `(a * b)` rotates by `b`, and then by `a`
+impl Mul for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.vector(), rhs.vector());
+        let [x, y, z]: [f32; 3] = (b * self.w + a * rhs.w + a.cross(&b)).into();
+        Self::new(x, y, z, self.w * rhs.w - a.dot(&b))
+    }
+}
+
+/// A quaternion can be converted to the vector of its coordinates `[x, y, z, w]`
+impl From<Quat> for Vec4 {
+    fn from(Quat { x, y, z, w }: Quat) -> Self {
+        [x, y, z, w].into()
+    }
+}
+
+/// A vector of coordinates `[x, y, z, w]` can be converted to a quaternion
+impl From<Vec4> for Quat {
+    fn from(coordinates: Vec4) -> Self {
+        let [x, y, z, w]: [f32; 4] = coordinates.into();
+        Self::new(x, y, z, w)
+    }
+}
+
+/// A quaternion can be converted to the homogeneous rotation matrix
+impl From<Quat> for Mat4 {
+    fn from(rotation: Quat) -> Self {
+        rotation.to_matrix()
+    }
+}
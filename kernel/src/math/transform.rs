@@ -0,0 +1,153 @@
+use super::{Mat4, Quat, Vec3};
+use std::ops::Mul;
+
+/// A transformation of an object, which scales it (non-uniformly) along its local axes,
+/// then rotates it, and then translates it.
+///
+/// Composition, and inversion of the transforms are exact, as long as the scaling
+/// is uniform. Otherwise, a rotation between two non-uniform scalings introduces a shear,
+/// which cannot be represented by a `Transform`, and is dropped; the matrices
+/// (`to_matrix`, `inverse_matrix`, `normal_matrix`) of a single transform are always exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// Translation of the object
+    pub translation: Vec3,
+
+    /// Rotation of the object
+    pub rotation: Quat,
+
+    /// Scaling of the object along its local axes
+    pub scale: Vec3,
+}
+
+impl Transform {
+    /// Creates the transform.
+    pub fn new(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Creates the identity transform.
+    pub fn identity() -> Self {
+        Self::new(
+            [0.0, 0.0, 0.0].into(),
+            Quat::identity(),
+            [1.0, 1.0, 1.0].into(),
+        )
+    }
+
+    /// Creates the translation.
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates the rotation.
+    pub fn from_rotation(rotation: Quat) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates the scaling.
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    /// Transforms the point.
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transforms the vector, i.e. scales, and rotates it, without the translation.
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rotation.rotate(hadamard(&self.scale, &vector))
+    }
+
+    /// Gets the inverse transform.
+    ///
+    /// The inverse is exact only for the uniform scaling (see `Transform`).
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        let scale = reciprocal(&self.scale);
+        let translation = hadamard(&scale, &rotation.rotate(self.translation)) * -1.0;
+        Self::new(translation, rotation, scale)
+    }
+
+    /// Interpolates the transforms: the translation, and the scale are interpolated linearly,
+    /// and the rotation is interpolated spherically.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.translation * (1.0 - t) + other.translation * t,
+            self.rotation.slerp(&other.rotation, t),
+            self.scale * (1.0 - t) + other.scale * t,
+        )
+    }
+
+    /// Gets the homogeneous matrix of the transform, i.e. the world matrix of the object.
+    pub fn to_matrix(&self) -> Mat4 {
+        Mat4::translation(self.translation) * self.rotation.to_matrix() * Mat4::scale(self.scale)
+    }
+
+    /// Gets the homogeneous matrix of the inverse transform, i.e. the view matrix of the object.
+    ///
+    /// Unlike `inverse().to_matrix()`, it is exact for the non-uniform scaling.
+    pub fn inverse_matrix(&self) -> Mat4 {
+        Mat4::scale(reciprocal(&self.scale))
+            * self.rotation.conjugate().to_matrix()
+            * Mat4::translation(self.translation * -1.0)
+    }
+
+    /// Gets the normal matrix, i.e. `transpose(inverse(to_matrix()))`.
+    pub fn normal_matrix(&self) -> Mat4 {
+        self.inverse_matrix().transpose()
+    }
+}
+
+/// The default transform is the identity
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Transforms are composed by the multiplication: `(a * b)` applies `b`, and then `a`.
+///
+/// The composition is exact only for the uniform scaling (see `Transform`).
+impl Mul for Transform {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation * rhs.rotation,
+            hadamard(&self.scale, &rhs.scale),
+        )
+    }
+}
+
+/// A transform can be converted to its homogeneous matrix
+impl From<Transform> for Mat4 {
+    fn from(transform: Transform) -> Self {
+        transform.to_matrix()
+    }
+}
+
+/// Inverts the elements of the vector
+fn reciprocal(a: &Vec3) -> Vec3 {
+    [1.0 / a[0], 1.0 / a[1], 1.0 / a[2]].into()
+}
+
+/// Multiplies the vectors element-wise
+fn hadamard(a: &Vec3, b: &Vec3) -> Vec3 {
+    [a[0] * b[0], a[1] * b[1], a[2] * b[2]].into()
+}
@@ -0,0 +1,77 @@
+use crate::{
+    abstract_runtime::{ClockworkEvent, ClockworkState},
+    clockwork::{ClockworkBuilder, ClockworkBuilderError},
+};
+
+/// A plugin is a bundle of mechanisms, which implements a single feature of the engine.
+///
+/// The substates, which the plugin requires, are declared as `Substate` bounds
+/// on the state `S` of the implementation, so a plugin cannot be added
+/// to a Clockwork, whose state lacks them.
+///
+/// Plugins are added with `ClockworkBuilder::add_plugin`, and are installed
+/// during `ClockworkBuilder::build` in the order of their addition:
+/// first, the configuration of the plugin is validated, and then the plugin
+/// adds its mechanisms (and, possibly, other plugins) to the builder.
+/// Errors of both steps fail the build.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::{abstract_runtime::*, clockwork::*, plugin::Plugin, prelude::*};
+/// struct Score(u32);
+/// impl ClockworkState for Score {}
+///
+/// struct ScorePlugin {
+///     points_per_tick: u32,
+/// }
+///
+/// impl<S> Plugin<S, StandardEvent> for ScorePlugin
+/// where
+///     S: Substate<Score>,
+/// {
+///     fn validate(&self) -> Result<(), String> {
+///         match self.points_per_tick {
+///             0 => Err("Points per tick must be positive".into()),
+///             _ => Ok(()),
+///         }
+///     }
+///
+///     fn install(
+///         self,
+///         builder: ClockworkBuilder<S, StandardEvent>,
+///     ) -> Result<ClockworkBuilder<S, StandardEvent>, ClockworkBuilderError> {
+///         let points = self.points_per_tick;
+///         Ok(builder.on(StandardEvent::Tick, move |state| {
+///             state.start_mutate().get_mut(|score: &mut Score| score.0 += points).finish()
+///         }))
+///     }
+/// }
+/// ```
+pub trait Plugin<S, E>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// The name of the plugin, which is used in logs, and in errors.
+    ///
+    /// The method has a default implementation, which returns the type name of the plugin.
+    fn name(&self) -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+
+    /// Validates the configuration of the plugin.
+    ///
+    /// An error fails `ClockworkBuilder::build`.
+    /// The method has a default implementation, which accepts every configuration.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Adds the mechanisms of the plugin to the builder.
+    ///
+    /// An error (e.g. a failure to build a mechanism of the plugin) fails `ClockworkBuilder::build`.
+    fn install(
+        self,
+        builder: ClockworkBuilder<S, E>,
+    ) -> Result<ClockworkBuilder<S, E>, ClockworkBuilderError>;
+}
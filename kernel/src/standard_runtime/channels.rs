@@ -0,0 +1,168 @@
+use super::{StandardEvent, StandardMechanism};
+use crate::abstract_runtime::{ClockworkState, EngineState, Substate};
+use std::{cmp, marker::PhantomData};
+
+/// A substate, which is a typed message channel between the mechanisms.
+///
+/// Any mechanism (or a legion system, which has it as a resource) may send
+/// the messages, while every reader keeps its own `EventReader` cursor,
+/// and therefore sees every message once.
+///
+/// The messages are double-buffered: `Events::update` drops the messages
+/// of the previous update, so the messages survive exactly one tick cycle,
+/// if the channel is updated by the `EventsMechanism`.
+#[derive(Debug)]
+pub struct Events<T> {
+    /// Messages, sent before the last update
+    previous: Vec<T>,
+
+    /// The index of the first previous message
+    previous_start: usize,
+
+    /// Messages, sent after the last update
+    current: Vec<T>,
+
+    /// The index of the first current message
+    current_start: usize,
+}
+impl<T> ClockworkState for Events<T> where T: 'static {}
+
+impl<T> Default for Events<T> {
+    fn default() -> Self {
+        Self {
+            previous: Vec::new(),
+            previous_start: 0,
+            current: Vec::new(),
+            current_start: 0,
+        }
+    }
+}
+
+impl<T> Events<T> {
+    /// Sends the message.
+    pub fn send(&mut self, message: T) {
+        self.current.push(message)
+    }
+
+    /// Sends the messages in their order.
+    pub fn send_batch(&mut self, messages: impl IntoIterator<Item = T>) {
+        self.current.extend(messages)
+    }
+
+    /// Drops the messages of the previous update, and starts the new one.
+    pub fn update(&mut self) {
+        self.previous = std::mem::take(&mut self.current);
+        self.previous_start = self.current_start;
+        self.current_start += self.previous.len();
+    }
+
+    /// Drops all the messages.
+    pub fn clear(&mut self) {
+        self.update();
+        self.update();
+    }
+
+    /// Creates a reader, which sees the messages, sent after its creation.
+    pub fn reader(&self) -> EventReader<T> {
+        EventReader {
+            cursor: self.end(),
+            phantom_data: Default::default(),
+        }
+    }
+
+    /// Gets the amount of the stored messages of both updates.
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    /// Checks, whether no messages are stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The index of the next sent message
+    fn end(&self) -> usize {
+        self.current_start + self.current.len()
+    }
+}
+
+/// A cursor of a reader of the `Events<T>` channel.
+///
+/// The reader is usually kept by the reading mechanism.
+/// A default reader sees every stored message.
+#[derive(Debug)]
+pub struct EventReader<T> {
+    /// The index of the next unread message
+    cursor: usize,
+
+    /// The message type
+    phantom_data: PhantomData<fn(&T)>,
+}
+
+impl<T> Default for EventReader<T> {
+    fn default() -> Self {
+        Self {
+            cursor: 0,
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<T> Clone for EventReader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cursor: self.cursor,
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<T> EventReader<T> {
+    /// Reads the unread messages in the order of their sending.
+    ///
+    /// The messages, which have been dropped before they were read, are skipped.
+    pub fn read<'a>(&mut self, events: &'a Events<T>) -> impl Iterator<Item = &'a T> {
+        let unread = |messages: &'a [T], start: usize| {
+            &messages[cmp::min(self.cursor.saturating_sub(start), messages.len())..]
+        };
+        let previous = unread(&events.previous, events.previous_start);
+        let current = unread(&events.current, events.current_start);
+        self.cursor = events.end();
+        previous.iter().chain(current)
+    }
+
+    /// Gets the amount of the unread messages.
+    pub fn len(&self, events: &Events<T>) -> usize {
+        events.end() - cmp::max(self.cursor, events.previous_start).min(events.end())
+    }
+
+    /// Checks, whether there are no unread messages.
+    pub fn is_empty(&self, events: &Events<T>) -> bool {
+        self.len(events) == 0
+    }
+}
+
+/// A mechanism, which updates the `Events<T>` channel upon every `LateTick`,
+/// so that the messages, sent during a tick cycle, are readable
+/// during this cycle, and the next one.
+pub struct EventsMechanism<T>(PhantomData<fn(&T)>);
+
+impl<T> Default for EventsMechanism<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<S, T> StandardMechanism<S> for EventsMechanism<T>
+where
+    S: Substate<Events<T>>,
+    T: 'static,
+{
+    fn late_tick(&mut self, state: &mut EngineState<S>) {
+        state.start_mutate().get_mut(Events::<T>::update).finish()
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        Some(vec![StandardEvent::LateTick])
+    }
+}
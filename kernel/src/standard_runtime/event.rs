@@ -1,13 +1,19 @@
 use std::convert::TryInto;
 
 use crate::abstract_runtime::ClockworkEvent;
+use serde::{Deserialize, Serialize};
 
 /// A base event of the Clockwork.
 ///
 /// Even if it is not required to blindly use this exact
 /// event type for all usecases, the variants of this enumeration
 /// represent the most important event types of every game engine runtime.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Standard main loops emit the events in the following order:
+/// `Initialization` once; then, for every tick, `FixedTick` (zero, or more times,
+/// depending on the elapsed time), `Tick`, and `LateTick`; for every frame,
+/// `PreDraw`, `Draw`, and `PostDraw`; and finally `Termination` once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StandardEvent {
     /// During this event, all mechanisms initialize their
     /// internal states, as well as the parts of a shared state,
@@ -29,6 +35,52 @@ pub enum StandardEvent {
     /// > For majority of cases, Rust language disposes all objects automatically, so this
     ///   kind of event does not have to be handled in all cases except for manual memory allocation.
     Termination,
+
+    /// During this event, all mechanisms advance the simulations, which require
+    /// a constant time step, by `StandardRuntimeStatistics::fixed_tick_delta`.
+    ///
+    /// The event is emitted right before `Tick` as many times, as the fixed time step
+    /// fits into the elapsed time, so it happens at a constant rate on average.
+    FixedTick,
+
+    /// During this event, all mechanisms react on the results of the tick,
+    /// e.g. a camera follows the moved objects.
+    ///
+    /// The event is emitted right after every `Tick`.
+    LateTick,
+
+    /// During this event, all mechanisms prepare the data for rendering,
+    /// e.g. build the user interface.
+    ///
+    /// The event is emitted right before every `Draw`.
+    PreDraw,
+
+    /// During this event, all mechanisms process the rendered frame,
+    /// e.g. capture it.
+    ///
+    /// The event is emitted right after every `Draw`.
+    PostDraw,
+}
+
+impl StandardEvent {
+    /// The events of a single tick (except for `FixedTick`) in the order of their emission
+    pub const TICK_PHASES: [StandardEvent; 2] = [StandardEvent::Tick, StandardEvent::LateTick];
+
+    /// The events of a single frame in the order of their emission
+    pub const DRAW_PHASES: [StandardEvent; 3] = [
+        StandardEvent::PreDraw,
+        StandardEvent::Draw,
+        StandardEvent::PostDraw,
+    ];
+}
+
+/// A standard event has no payload, so it is its own kind.
+impl ClockworkEvent for StandardEvent {
+    type Kind = Self;
+
+    fn kind(&self) -> Self::Kind {
+        *self
+    }
 }
 
 /// A trait, which is automatically implemented for every custom ClockworkEvent,
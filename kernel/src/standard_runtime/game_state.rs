@@ -0,0 +1,335 @@
+use super::{StandardEvent, StandardEventSuperset};
+use crate::abstract_runtime::{ClockworkEvent, ClockworkState, EngineState, Mechanism, Substate};
+use log::*;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// The maximum amount of transitions, which are applied after a single event.
+///
+/// Hooks may request further transitions, so the limit breaks the transition cycles.
+const MAX_TRANSITIONS_PER_EVENT: usize = 16;
+
+/// A substate, which keeps the current state of the game state machine
+/// (e.g. main menu, loading, playing, or paused).
+///
+/// Transitions are requested by the mechanisms, and are applied between the events
+/// by the `GameStateMachine`, so the state does not change during an event.
+#[derive(Debug)]
+pub struct GameState<G> {
+    /// The current state
+    current: G,
+
+    /// The requested state
+    requested: Option<G>,
+}
+impl<G> ClockworkState for GameState<G> where G: 'static {}
+
+impl<G> GameState<G>
+where
+    G: Clone + PartialEq + Debug + 'static,
+{
+    /// Creates the substate in the initial state.
+    pub fn new(initial: G) -> Self {
+        Self {
+            current: initial,
+            requested: None,
+        }
+    }
+
+    /// Gets the current state.
+    pub fn current(&self) -> &G {
+        &self.current
+    }
+
+    /// Checks, whether the machine is in the state.
+    pub fn is(&self, state: &G) -> bool {
+        &self.current == state
+    }
+
+    /// Requests the transition to the state.
+    ///
+    /// The transition is applied after the current event.
+    /// If several transitions are requested during an event, the last one is applied.
+    pub fn request_transition(&mut self, next: G) {
+        self.requested = Some(next)
+    }
+
+    /// Gets the requested state, which is not applied yet.
+    pub fn requested_transition(&self) -> Option<&G> {
+        self.requested.as_ref()
+    }
+}
+
+/// A hook, which is called upon entering, exiting, or updating a game state
+type Hook<S> = Box<dyn FnMut(&mut EngineState<S>)>;
+
+/// A state machine, which applies the requested transitions of the `GameState<G>` substate,
+/// and calls the hooks of the states.
+///
+/// - on-enter hooks are called upon the initialization for the initial state,
+///   and after every transition for the new state;
+/// - on-exit hooks are called before every transition for the old state;
+/// - on-update hooks are called upon every tick for the current state.
+///
+/// The machine is added with `ClockworkBuilder::add_game_state_machine`, and is run
+/// by `Mechanisms` after every event (including the follow-up events), once all the mechanisms
+/// have been clinked, so the transitions, requested during the event, are applied before the next one.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::{abstract_runtime::*, prelude::*, standard_runtime::*};
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Mode {
+///     Playing,
+///     Paused,
+/// }
+///
+/// #[derive(ClockworkState)]
+/// struct State {
+///     mode: GameState<Mode>,
+/// }
+///
+/// let machine = GameStateMachine::<State, Mode>::default()
+///     .on_enter(Mode::Paused, |_| info!("Paused"))
+///     .on_update(Mode::Playing, |state| state.request_transition(Mode::Paused));
+/// let builder = Clockwork::<State, StandardEvent>::builder().add_game_state_machine(machine);
+/// ```
+pub struct GameStateMachine<S, G>
+where
+    S: ClockworkState,
+{
+    /// On-enter hooks of the states
+    on_enter: Vec<(G, Hook<S>)>,
+
+    /// On-exit hooks of the states
+    on_exit: Vec<(G, Hook<S>)>,
+
+    /// On-update hooks of the states
+    on_update: Vec<(G, Hook<S>)>,
+}
+
+impl<S, G> Default for GameStateMachine<S, G>
+where
+    S: ClockworkState,
+{
+    fn default() -> Self {
+        Self {
+            on_enter: Default::default(),
+            on_exit: Default::default(),
+            on_update: Default::default(),
+        }
+    }
+}
+
+impl<S, G> GameStateMachine<S, G>
+where
+    S: Substate<GameState<G>>,
+    G: Clone + PartialEq + Debug + 'static,
+{
+    /// Adds the hook, which is called upon entering the state.
+    pub fn on_enter(mut self, state: G, hook: impl FnMut(&mut EngineState<S>) + 'static) -> Self {
+        self.on_enter.push((state, Box::new(hook)));
+        self
+    }
+
+    /// Adds the hook, which is called upon exiting the state.
+    pub fn on_exit(mut self, state: G, hook: impl FnMut(&mut EngineState<S>) + 'static) -> Self {
+        self.on_exit.push((state, Box::new(hook)));
+        self
+    }
+
+    /// Adds the hook, which is called upon every tick in the state.
+    pub fn on_update(mut self, state: G, hook: impl FnMut(&mut EngineState<S>) + 'static) -> Self {
+        self.on_update.push((state, Box::new(hook)));
+        self
+    }
+
+    /// Calls the hooks of the event, then applies the transitions, requested during the event.
+    ///
+    /// This method is crate-private, and is called by `Mechanisms` after every event.
+    /// Use `ClockworkBuilder::add_game_state_machine` to add the machine into clockwork.
+    pub(crate) fn after_event<E>(&mut self, state: &mut EngineState<S>, event: &E)
+    where
+        E: StandardEventSuperset,
+    {
+        match event.clone().try_into() {
+            Ok(StandardEvent::Initialization) => {
+                let current = Self::current(state);
+                Self::call_hooks(&mut self.on_enter, &current, state)
+            }
+            Ok(StandardEvent::Tick) => {
+                let current = Self::current(state);
+                Self::call_hooks(&mut self.on_update, &current, state)
+            }
+            _ => {}
+        }
+        self.apply_transitions(state)
+    }
+
+    /// Applies the requested transitions
+    fn apply_transitions(&mut self, state: &mut EngineState<S>) {
+        for _ in 0..MAX_TRANSITIONS_PER_EVENT {
+            let (current, next) = match state
+                .start_mutate()
+                .get_mut(|game_state: &mut GameState<G>| {
+                    let next = game_state.requested.take()?;
+                    Some((game_state.current.clone(), next))
+                })
+                .finish()
+            {
+                Some(transition) => transition,
+                None => return,
+            };
+            if current == next {
+                continue;
+            }
+            debug!("Game state transition: {:?} -> {:?}", current, next);
+            Self::call_hooks(&mut self.on_exit, &current, state);
+            state
+                .start_mutate()
+                .get_mut(|game_state: &mut GameState<G>| game_state.current = next.clone())
+                .finish();
+            Self::call_hooks(&mut self.on_enter, &next, state);
+        }
+        let postponed = state
+            .start_access()
+            .get(|game_state: &GameState<G>| game_state.requested.is_some())
+            .finish();
+        if postponed {
+            error!(
+                "Postponed game state transition: more than {} transitions in a single event",
+                MAX_TRANSITIONS_PER_EVENT
+            )
+        }
+    }
+
+    /// Calls the hooks of the state
+    fn call_hooks(hooks: &mut [(G, Hook<S>)], game_state: &G, state: &mut EngineState<S>) {
+        hooks
+            .iter_mut()
+            .filter(|(hooked, _)| hooked == game_state)
+            .for_each(|(_, hook)| hook(state))
+    }
+
+    /// Gets the current state
+    fn current(state: &EngineState<S>) -> G {
+        state
+            .start_access()
+            .get(|game_state: &GameState<G>| game_state.current.clone())
+            .finish()
+    }
+}
+
+/// Gates the mechanisms by the `GameState<G>` substate.
+///
+/// The gated mechanism keeps the state type of the original one, so the mechanisms,
+/// which are generic over the state (e.g. `LegionSystems`), are gated for the state
+/// of the engine, to which they are added.
+///
+/// Example:
+/// ```
+/// # use spc_clockwork_kernel::{abstract_runtime::*, prelude::*, standard_runtime::*};
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Mode {
+///     Playing,
+///     Paused,
+/// }
+///
+/// #[derive(ClockworkState)]
+/// struct State {
+///     mode: GameState<Mode>,
+/// }
+///
+/// let physics = ClosureMechanism::new(
+///     Some(vec![StandardEvent::FixedTick]),
+///     |_: &mut EngineState<State>, _: StandardEvent| trace!("Physics step"),
+/// );
+/// let builder = Clockwork::<State, StandardEvent>::builder()
+///     .add_mechanism(physics.in_game_states(vec![Mode::Playing]));
+/// ```
+pub trait GameStateMechanismExt<S, E>
+where
+    Self: Mechanism<S, E> + Sized,
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+    /// Only passes the events to the mechanism, while the `GameState<G>` substate
+    /// is in one of the states.
+    fn in_game_states<G>(self, states: impl IntoIterator<Item = G>) -> InGameStates<Self, G, S, E>
+    where
+        S: Substate<GameState<G>>,
+        G: Clone + PartialEq + Debug + 'static,
+    {
+        InGameStates {
+            mechanism: self,
+            states: states.into_iter().collect(),
+            phantom_data: Default::default(),
+        }
+    }
+}
+impl<S, E, M> GameStateMechanismExt<S, E> for M
+where
+    M: Mechanism<S, E>,
+    S: ClockworkState,
+    E: ClockworkEvent,
+{
+}
+
+/// A mechanism, which is only clinked, while the game is in one of the states.
+///
+/// See `GameStateMechanismExt::in_game_states`.
+pub struct InGameStates<M, G, S, E> {
+    /// The original mechanism
+    mechanism: M,
+
+    /// The states, in which the mechanism is clinked
+    states: Vec<G>,
+
+    /// Phantom data for state and event types
+    phantom_data: PhantomData<(S, E)>,
+}
+
+impl<S, E, M, G> Mechanism<S, E> for InGameStates<M, G, S, E>
+where
+    S: Substate<GameState<G>>,
+    E: ClockworkEvent,
+    M: Mechanism<S, E>,
+    G: Clone + PartialEq + Debug + 'static,
+{
+    fn clink(&mut self, state: &mut EngineState<S>, event: E) {
+        let states = &self.states;
+        if state
+            .start_access()
+            .get(|game_state: &GameState<G>| states.contains(game_state.current()))
+            .finish()
+        {
+            self.mechanism.clink(state, event)
+        }
+    }
+
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
+        self.mechanism.handled_events()
+    }
+
+    fn removal(&mut self, state: &mut EngineState<S>) {
+        self.mechanism.removal(state)
+    }
+}
+
+/// Game state transitions
+impl<S> EngineState<S>
+where
+    S: ClockworkState,
+{
+    /// Requests the transition of the `GameState<G>` substate.
+    ///
+    /// See `GameState::request_transition` for details.
+    pub fn request_transition<G>(&mut self, next: G)
+    where
+        S: Substate<GameState<G>>,
+        G: Clone + PartialEq + Debug + 'static,
+    {
+        self.start_mutate()
+            .get_mut(|game_state: &mut GameState<G>| game_state.request_transition(next))
+            .finish()
+    }
+}
@@ -1,8 +1,31 @@
 use super::{StandardEvent, StandardEventSuperset};
-use crate::abstract_runtime::{ClockworkState, EngineState, Mechanism};
+use crate::abstract_runtime::{ClockworkState, EngineState, Mechanism, MechanismOrdering};
 use std::{convert::TryInto, marker::PhantomData};
 
 /// A subset of Mechanisms, which is meant to work with the BaseEvent.
+///
+/// Every handler method has a default implementation, which does nothing,
+/// so only the handlers of the events, which the mechanism reacts upon, must be defined,
+/// alongside with `handled_events`.
+///
+/// The `#[standard_mechanism]` attribute on the impl block generates `handled_events`
+/// from the handlers, which are defined in the block:
+/// ```
+/// # use spc_clockwork_kernel::abstract_runtime::{ClockworkState, EngineState};
+/// # use spc_clockwork_kernel::standard_runtime::*;
+/// # struct State;
+/// # impl ClockworkState for State {}
+/// struct Counter(u64);
+///
+/// #[standard_mechanism]
+/// impl StandardMechanism<State> for Counter {
+///     fn tick(&mut self, _: &mut EngineState<State>) {
+///         self.0 += 1
+///     }
+/// }
+///
+/// assert_eq!(Counter(0).handled_events(), Some(vec![StandardEvent::Tick]));
+/// ```
 pub trait StandardMechanism<S>
 where
     S: ClockworkState,
@@ -16,7 +39,7 @@ where
     /// During this event, the mechanism must initialize its internal state
     /// (if its state depends on the shared state),
     /// as well as its part of the shared sate.
-    fn initialization(&mut self, state: &mut EngineState<S>);
+    fn initialization(&mut self, _state: &mut EngineState<S>) {}
 
     /// This handler method is expected to be called repetitively (once per tick) at
     /// the runtime of Clockwork's main loop.
@@ -26,7 +49,35 @@ where
     ///
     /// During this event, the mechanism must update its internal state,
     /// as well as its part of the shared state, according to its logic.
-    fn tick(&mut self, state: &mut EngineState<S>);
+    fn tick(&mut self, _state: &mut EngineState<S>) {}
+
+    /// This handler method is expected to be called repetitively (at a constant rate
+    /// on average) at the runtime of Clockwork's main loop, right before the tick.
+    ///
+    /// The invocation of this method is equivalent to `Mechanism::clink`
+    /// with `BaseEvent::FixedTick` as the event parameter.
+    ///
+    /// During this event, the mechanism must advance its simulation
+    /// by a constant time step (see `StandardRuntimeStatistics::fixed_tick_delta`).
+    fn fixed_tick(&mut self, _state: &mut EngineState<S>) {}
+
+    /// This handler method is expected to be called repetitively (once per tick) at
+    /// the runtime of Clockwork's main loop, right after the tick.
+    ///
+    /// The invocation of this method is equivalent to `Mechanism::clink`
+    /// with `BaseEvent::LateTick` as the event parameter.
+    ///
+    /// During this event, the mechanism may react on the state, updated by the tick.
+    fn late_tick(&mut self, _state: &mut EngineState<S>) {}
+
+    /// This handler method is expected to be called repetitively (once per draw call) at
+    /// the runtime of Clockwork's main loop, right before the draw call.
+    ///
+    /// The invocation of this method is equivalent to `Mechanism::clink`
+    /// with `BaseEvent::PreDraw` as the event parameter.
+    ///
+    /// During this event, the mechanism must prepare the data for rendering.
+    fn pre_draw(&mut self, _state: &mut EngineState<S>) {}
 
     /// This handler method is expected to be called repetitively (once per draw call) at
     /// the runtime of Clockwork's main loop.
@@ -37,7 +88,16 @@ where
     /// During this event, the mechanism may update its internal state,
     /// but this stage actually is dedicated for scheduling IO-bound operations,
     /// such as rendering.
-    fn draw(&mut self, state: &mut EngineState<S>);
+    fn draw(&mut self, _state: &mut EngineState<S>) {}
+
+    /// This handler method is expected to be called repetitively (once per draw call) at
+    /// the runtime of Clockwork's main loop, right after the draw call.
+    ///
+    /// The invocation of this method is equivalent to `Mechanism::clink`
+    /// with `BaseEvent::PostDraw` as the event parameter.
+    ///
+    /// During this event, the mechanism may process the drawn frame.
+    fn post_draw(&mut self, _state: &mut EngineState<S>) {}
 
     /// This handler method is expected to be called once at
     /// the end of Clockwork runtime.
@@ -50,21 +110,23 @@ where
     ///
     /// > Note that for majority of cases, Rust language disposes all objects automatically, so this
     ///   kind of event does not have to be handled in all cases except for manual memory allocation.
-    fn termination(&mut self, state: &mut EngineState<S>);
+    fn termination(&mut self, _state: &mut EngineState<S>) {}
 
     /// Defines a set of events, which this mechanism is handling.
     /// The method is called once during the mechanisms assembly.
     /// If None is returned, then the mechanism will be clinked upon every event.
-    /// It is recommended to implement this manually, as it might save some cpu
-    /// resources, especially if there is a complex event system, or big amount of
-    /// mechanisms.
+    /// It is recommended to return the events, whose handlers are defined,
+    /// as it might save some cpu resources, especially if there is a complex event system,
+    /// or big amount of mechanisms.
+    ///
+    /// The method is generated by the `#[standard_mechanism]` attribute.
     fn handled_events(&self) -> Option<Vec<StandardEvent>>;
 }
 
 /// A wrapper for the BaseEventMechanism.
 ///
 /// This structure is used in order to give impl Mechanism to every instance
-/// of BaseEventMechanism
+/// of BaseEventMechanism.
 pub(crate) struct StandardMechanismWrapper<T, S>(T, PhantomData<S>)
 where
     T: StandardMechanism<S>,
@@ -90,17 +152,53 @@ where
         TryInto::<StandardEvent>::try_into(event)
             .map(|event| match event {
                 StandardEvent::Initialization => T::initialization,
+                StandardEvent::FixedTick => T::fixed_tick,
                 StandardEvent::Tick => T::tick,
+                StandardEvent::LateTick => T::late_tick,
+                StandardEvent::PreDraw => T::pre_draw,
                 StandardEvent::Draw => T::draw,
+                StandardEvent::PostDraw => T::post_draw,
                 StandardEvent::Termination => T::termination,
             })
             .map_or((), |f| f(&mut self.0, state))
     }
 
-    fn handled_events(&self) -> Option<Vec<E>> {
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
         StandardMechanism::handled_events(&self.0)
             .map(IntoIterator::into_iter)
-            .map(|el| el.map(Into::into))
+            .map(|el| el.map(|event| E::from(event).kind()))
             .map(Iterator::collect)
     }
+
+    /// A standard mechanism, which is removed at runtime, is terminated,
+    /// but only if it handles the termination event.
+    fn removal(&mut self, state: &mut EngineState<S>) {
+        let handles_termination = match StandardMechanism::handled_events(&self.0) {
+            Some(events) => events.contains(&StandardEvent::Termination),
+            None => true,
+        };
+        if handles_termination {
+            self.0.termination(state)
+        }
+    }
+}
+
+/// Runtime commands for standard mechanisms
+impl<S> EngineState<S>
+where
+    S: ClockworkState,
+{
+    /// Converts StandardMechanism into the instance of Mechanism,
+    /// then queues the addition of this mechanism.
+    ///
+    /// See `EngineState::add_mechanism` for details.
+    pub fn add_standard_mechanism<E>(
+        &mut self,
+        mechanism: impl StandardMechanism<S> + 'static,
+        ordering: MechanismOrdering,
+    ) where
+        E: StandardEventSuperset,
+    {
+        self.add_mechanism::<E>(StandardMechanismWrapper::from(mechanism), ordering)
+    }
 }
@@ -0,0 +1,236 @@
+use super::{StandardEvent, StandardMechanism, StandardRuntimeStatistics};
+use crate::abstract_runtime::{ClockworkEvent, ClockworkState, EngineState, Substate};
+use std::{collections::BTreeMap, marker::PhantomData, time::Duration};
+
+/// A handle of a scheduled timer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimerHandle(u64);
+
+/// A scheduled timer
+#[derive(Debug)]
+struct Timer<E> {
+    /// The event, which is emitted, when the timer is due
+    event: E,
+
+    /// The tick time, which remains until the timer is due
+    remaining: Duration,
+
+    /// The period of a repeating timer, or None for a one-shot one
+    period: Option<Duration>,
+
+    /// Whether the timer is paused
+    paused: bool,
+}
+
+/// A substate, which keeps the delayed, and the periodic events.
+///
+/// Timers are measured in tick time: they are advanced by the tick delta
+/// of `StandardRuntimeStatistics`, multiplied by the time scale of the scheduler,
+/// so the timers stop, while the ticks are not happening.
+///
+/// Due events are emitted by the `SchedulerMechanism` as follow-up events of the tick,
+/// in the order, in which they became due.
+#[derive(Debug)]
+pub struct Scheduler<E>
+where
+    E: ClockworkEvent,
+{
+    /// The handle of the next scheduled timer
+    next_handle: u64,
+
+    /// Scheduled timers
+    timers: BTreeMap<TimerHandle, Timer<E>>,
+
+    /// Whether all the timers are paused
+    paused: bool,
+
+    /// The multiplier of the tick delta
+    time_scale: f32,
+}
+impl<E> ClockworkState for Scheduler<E> where E: ClockworkEvent {}
+
+impl<E> Default for Scheduler<E>
+where
+    E: ClockworkEvent,
+{
+    fn default() -> Self {
+        Self {
+            next_handle: 0,
+            timers: Default::default(),
+            paused: false,
+            time_scale: 1f32,
+        }
+    }
+}
+
+impl<E> Scheduler<E>
+where
+    E: ClockworkEvent,
+{
+    /// Schedules the event to be emitted once after the delay.
+    pub fn schedule_once(&mut self, delay: Duration, event: E) -> TimerHandle {
+        self.schedule(delay, None, event)
+    }
+
+    /// Schedules the event to be emitted every period, starting after the first period.
+    ///
+    /// Panics, if the period is zero.
+    pub fn schedule_repeating(&mut self, period: Duration, event: E) -> TimerHandle {
+        assert!(
+            period > Duration::default(),
+            "Repeating timers must have a non-zero period"
+        );
+        self.schedule(period, Some(period), event)
+    }
+
+    /// Cancels the timer.
+    ///
+    /// Returns false, if the timer is not scheduled (e.g. a one-shot timer is already due).
+    pub fn cancel(&mut self, handle: TimerHandle) -> bool {
+        self.timers.remove(&handle).is_some()
+    }
+
+    /// Pauses the timer, keeping its remaining time.
+    pub fn pause(&mut self, handle: TimerHandle) {
+        self.set_paused(handle, true)
+    }
+
+    /// Resumes the paused timer.
+    pub fn resume(&mut self, handle: TimerHandle) {
+        self.set_paused(handle, false)
+    }
+
+    /// Checks, whether the timer is scheduled.
+    pub fn is_scheduled(&self, handle: TimerHandle) -> bool {
+        self.timers.contains_key(&handle)
+    }
+
+    /// Gets the tick time, which remains until the timer is due.
+    pub fn remaining(&self, handle: TimerHandle) -> Option<Duration> {
+        self.timers.get(&handle).map(|timer| timer.remaining)
+    }
+
+    /// Pauses all the timers.
+    pub fn pause_all(&mut self) {
+        self.paused = true
+    }
+
+    /// Resumes all the timers, except for the individually paused ones.
+    pub fn resume_all(&mut self) {
+        self.paused = false
+    }
+
+    /// Checks, whether all the timers are paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets the multiplier of the tick delta.
+    ///
+    /// Panics, if the scale is negative, or not finite.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        assert!(
+            time_scale.is_finite() && time_scale >= 0f32,
+            "Time scale must be a finite non-negative number"
+        );
+        self.time_scale = time_scale
+    }
+
+    /// Gets the multiplier of the tick delta.
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Advances the timers by the tick delta,
+    /// and returns the events of the due timers in the order of their due time.
+    ///
+    /// This method is crate-private, and is called by `SchedulerMechanism`.
+    pub(crate) fn advance(&mut self, delta: Duration) -> Vec<E> {
+        if self.paused {
+            return Vec::new();
+        }
+        let delta = delta.mul_f32(self.time_scale);
+        let mut due = Vec::new();
+        self.timers.retain(|&handle, timer| {
+            if timer.paused {
+                return true;
+            }
+            let mut left = delta;
+            while timer.remaining <= left {
+                left -= timer.remaining;
+                due.push((delta - left, handle, timer.event.clone()));
+                match timer.period {
+                    Some(period) => timer.remaining = period,
+                    None => return false,
+                }
+            }
+            timer.remaining -= left;
+            true
+        });
+        due.sort_by_key(|&(due_at, handle, _)| (due_at, handle));
+        due.into_iter().map(|(_, _, event)| event).collect()
+    }
+
+    /// Adds the timer
+    fn schedule(&mut self, delay: Duration, period: Option<Duration>, event: E) -> TimerHandle {
+        let handle = TimerHandle(self.next_handle);
+        self.next_handle += 1;
+        self.timers.insert(
+            handle,
+            Timer {
+                event,
+                remaining: delay,
+                period,
+                paused: false,
+            },
+        );
+        handle
+    }
+
+    /// Sets the pause of the timer, if it is scheduled
+    fn set_paused(&mut self, handle: TimerHandle, paused: bool) {
+        if let Some(timer) = self.timers.get_mut(&handle) {
+            timer.paused = paused
+        }
+    }
+}
+
+/// A mechanism, which advances the `Scheduler<E>` substate on every tick
+/// by the tick delta of the statistics `T`, and emits the due events.
+///
+/// The event type `E` must be the event type of the Clockwork.
+pub struct SchedulerMechanism<E, T>(PhantomData<(E, T)>)
+where
+    E: ClockworkEvent,
+    T: StandardRuntimeStatistics;
+
+impl<E, T> Default for SchedulerMechanism<E, T>
+where
+    E: ClockworkEvent,
+    T: StandardRuntimeStatistics,
+{
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<S, E, T> StandardMechanism<S> for SchedulerMechanism<E, T>
+where
+    S: Substate<Scheduler<E>> + Substate<T>,
+    E: ClockworkEvent,
+    T: StandardRuntimeStatistics,
+{
+    fn tick(&mut self, state: &mut EngineState<S>) {
+        state
+            .start_mutate()
+            .get(T::current_tick_delta)
+            .then_get_mut(|delta, scheduler: &mut Scheduler<E>| scheduler.advance(delta))
+            .finish()
+            .into_iter()
+            .for_each(|event| state.emit_event(event))
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        Some(vec![StandardEvent::Tick])
+    }
+}
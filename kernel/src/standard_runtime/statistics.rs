@@ -50,6 +50,14 @@ where
         <Self as StandardRuntimeStatistics>::duration_to_freq(self.desired_avg_tick_delta())
     }
 
+    /// Gets the time step of the `StandardEvent::FixedTick`.
+    ///
+    /// The method has a default implementation, which returns
+    /// the desired average tick delta.
+    fn fixed_tick_delta(&self) -> Duration {
+        self.desired_avg_tick_delta()
+    }
+
     /// Gets the actual draw delta.
     ///
     /// Draw delta is the time duration between previous
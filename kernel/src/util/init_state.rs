@@ -1,4 +1,5 @@
 use crate::abstract_runtime::{ClockworkState, ClockworkStateRequirements};
+use thiserror::Error;
 
 /// Initializable state
 ///
@@ -10,6 +11,11 @@ use crate::abstract_runtime::{ClockworkState, ClockworkStateRequirements};
 ///    and cannot be used anymore.
 ///
 /// These stages follow each other during the application runtime.
+/// The state may be restarted from the initialized, or the terminated stage
+/// back to the uninitialized one (e.g. to rebuild it after a device loss).
+///
+/// Accessing the state in a wrong stage either panics, or, with the `try_` accessors,
+/// returns an `InitStateError`.
 #[derive(Clone, Debug)]
 pub enum InitState<U, T> {
     /// Uninitialized state
@@ -22,6 +28,22 @@ pub enum InitState<U, T> {
     Terminated,
 }
 
+/// An error of accessing the `InitState` in a wrong stage.
+///
+/// Every variant names the stage, in which the state has been,
+/// and carries the attempted action.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitStateError {
+    #[error("{0}, but the state has not yet been initialized")]
+    Uninit(&'static str),
+
+    #[error("{0}, but the state has already been initialized")]
+    Init(&'static str),
+
+    #[error("{0}, but the state has already been terminated")]
+    Terminated(&'static str),
+}
+
 /// An interface for the Uninit variant
 impl<U, T> InitState<U, T> {
     /// Initializes the state, given a mapping closure from the
@@ -32,10 +54,27 @@ impl<U, T> InitState<U, T> {
     /// # Panics
     /// Panics if the state is already initialized.
     pub fn initialize(&mut self, callback: impl FnOnce(U) -> T) {
-        *self = InitState::Init(match std::mem::replace(self, InitState::Terminated) {
-            InitState::Uninit(inner) => callback(inner),
-            other => other.on_bad_access("Tried to initialize the state"),
-        })
+        self.try_initialize(callback)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Initializes the state, given a mapping closure from the
+    /// uninitialized state to the initialized state.
+    ///
+    /// > The enum variant is replaced from Uninit to Init.
+    ///
+    /// Fails, and leaves the state intact, if the state is not uninitialized.
+    pub fn try_initialize(&mut self, callback: impl FnOnce(U) -> T) -> Result<(), InitStateError> {
+        match std::mem::replace(self, InitState::Terminated) {
+            InitState::Uninit(inner) => {
+                *self = InitState::Init(callback(inner));
+                Ok(())
+            }
+            other => {
+                *self = other;
+                Err(self.bad_access("Tried to initialize the state"))
+            }
+        }
     }
 
     /// Returns an immutable reference to the inner state, if the state is uninitialized.
@@ -43,9 +82,15 @@ impl<U, T> InitState<U, T> {
     /// # Panics
     /// Panics if the state has been initialized.
     pub fn get_uninit(&self) -> &U {
+        self.try_get_uninit()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns an immutable reference to the inner state, if the state is uninitialized.
+    pub fn try_get_uninit(&self) -> Result<&U, InitStateError> {
         match self {
-            InitState::Uninit(x) => x,
-            _ => self.on_bad_access("Tried to read the uninitialized state"),
+            InitState::Uninit(x) => Ok(x),
+            _ => Err(self.bad_access("Tried to read the uninitialized state")),
         }
     }
 
@@ -54,9 +99,15 @@ impl<U, T> InitState<U, T> {
     /// # Panics
     /// Panics if the state has been initialized.
     pub fn get_uninit_mut(&mut self) -> &mut U {
+        self.try_get_uninit_mut()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a mutable reference to the inner state, if the state is uninitialized.
+    pub fn try_get_uninit_mut(&mut self) -> Result<&mut U, InitStateError> {
         match self {
-            InitState::Uninit(x) => x,
-            _ => self.on_bad_access("Tried to write to the uninitialized state"),
+            InitState::Uninit(x) => Ok(x),
+            _ => Err(self.bad_access("Tried to write to the uninitialized state")),
         }
     }
 }
@@ -72,12 +123,28 @@ where
     /// > The enum variant is replaced from Init to Terminated.
     ///
     /// # Panics
-    /// Panics if the state is already initialized.
+    /// Panics if the state is not initialized.
     pub fn terminate(&mut self, callback: impl FnOnce(T)) {
+        self.try_terminate(callback)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Terminates the state, given some callback closure for state disposal.
+    ///
+    /// > The enum variant is replaced from Init to Terminated.
+    ///
+    /// Fails, and leaves the state intact, if the state is not initialized.
+    pub fn try_terminate(&mut self, callback: impl FnOnce(T)) -> Result<(), InitStateError> {
         match std::mem::replace(self, InitState::Terminated) {
-            InitState::Init(x) => callback(x),
-            _ => self.on_bad_access("Tried to terminate the state"),
-        };
+            InitState::Init(x) => {
+                callback(x);
+                Ok(())
+            }
+            other => {
+                *self = other;
+                Err(self.bad_access("Tried to terminate the state"))
+            }
+        }
     }
 
     /// Returns an immutable reference to the inner state, if the state is initialized.
@@ -85,9 +152,15 @@ where
     /// # Panics
     /// Panics if the state has been terminated, or has not been initialized.
     pub fn get_init(&self) -> &T {
+        self.try_get_init()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns an immutable reference to the inner state, if the state is initialized.
+    pub fn try_get_init(&self) -> Result<&T, InitStateError> {
         match self {
-            InitState::Init(x) => x,
-            _ => self.on_bad_access("Tried to read the state"),
+            InitState::Init(x) => Ok(x),
+            _ => Err(self.bad_access("Tried to read the state")),
         }
     }
 
@@ -96,28 +169,79 @@ where
     /// # Panics
     /// Panics if the state has been terminated, or has not been initialized.
     pub fn get_init_mut(&mut self) -> &mut T {
+        self.try_get_init_mut()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a mutable reference to the inner state, if the state is initialized.
+    pub fn try_get_init_mut(&mut self) -> Result<&mut T, InitStateError> {
         match self {
-            InitState::Init(x) => x,
-            _ => self.on_bad_access("Tried to write to the state"),
+            InitState::Init(x) => Ok(x),
+            _ => Err(self.bad_access("Tried to write to the state")),
         }
     }
 }
 
+/// An interface for the restart
+impl<U, T> InitState<U, T> {
+    /// Restarts the state, given a closure, which disposes the initialized state (if any),
+    /// and produces the new uninitialized state.
+    ///
+    /// > The enum variant is replaced from Init, or Terminated to Uninit.
+    ///
+    /// # Panics
+    /// Panics if the state is not initialized yet.
+    pub fn restart(&mut self, callback: impl FnOnce(Option<T>) -> U) {
+        self.try_restart(callback)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Restarts the state, given a closure, which disposes the initialized state (if any),
+    /// and produces the new uninitialized state.
+    ///
+    /// > The enum variant is replaced from Init, or Terminated to Uninit.
+    ///
+    /// Fails, and leaves the state intact, if the state is not initialized yet.
+    pub fn try_restart(
+        &mut self,
+        callback: impl FnOnce(Option<T>) -> U,
+    ) -> Result<(), InitStateError> {
+        *self = InitState::Uninit(match std::mem::replace(self, InitState::Terminated) {
+            InitState::Init(x) => callback(Some(x)),
+            InitState::Terminated => callback(None),
+            uninit => {
+                *self = uninit;
+                return Err(self.bad_access("Tried to restart the state"));
+            }
+        });
+        Ok(())
+    }
+
+    /// Checks, whether the state is uninitialized.
+    pub fn is_uninit(&self) -> bool {
+        matches!(self, InitState::Uninit(_))
+    }
+
+    /// Checks, whether the state is initialized.
+    pub fn is_init(&self) -> bool {
+        matches!(self, InitState::Init(_))
+    }
+
+    /// Checks, whether the state is terminated.
+    pub fn is_terminated(&self) -> bool {
+        matches!(self, InitState::Terminated)
+    }
+}
+
 /// Misc
 impl<U, T> InitState<U, T> {
-    /// Raises an error depending on the enum variant and the action.
+    /// Creates an error depending on the enum variant and the action.
     #[inline(always)]
-    fn on_bad_access<V>(&self, action_msg: &'static str) -> V {
+    fn bad_access(&self, action_msg: &'static str) -> InitStateError {
         match self {
-            InitState::Uninit(_) => {
-                panic!("{}, but the state has not yet been initialized", action_msg)
-            }
-            InitState::Init(_) => {
-                panic!("{}, but the state has already been initialized", action_msg)
-            }
-            InitState::Terminated => {
-                panic!("{}, but the state has already been terminated", action_msg)
-            }
+            InitState::Uninit(_) => InitStateError::Uninit(action_msg),
+            InitState::Init(_) => InitStateError::Init(action_msg),
+            InitState::Terminated => InitStateError::Terminated(action_msg),
         }
     }
 }
@@ -3,17 +3,135 @@ use no_deadlocks::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 #[cfg(not(debug_assertions))]
 use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
+use log::*;
 use std::{
+    mem::ManuallyDrop,
     ops::{Deref, DerefMut},
+    panic::Location,
     sync::{
-        atomic::{AtomicUsize, Ordering::Relaxed},
-        Arc,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering::Relaxed},
+        Arc, Mutex, PoisonError, TryLockError,
     },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
 };
+use thiserror::Error;
 
 static GLOBAL_LOCK_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-pub struct WriteLock<T>(usize, Arc<RwLock<T>>);
+static GLOBAL_SUBSCRIPTION_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The pause between the attempts of the timed lock acquisition
+const TIMED_LOCK_BACKOFF: Duration = Duration::from_micros(50);
+
+/// An error of the lock acquisition.
+///
+/// In debug builds, the errors carry the location, at which the current
+/// write guard of the lock has been acquired.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockError {
+    #[error("Lock {id} is held by another guard{}", holder_suffix(.holder))]
+    WouldBlock {
+        id: usize,
+        holder: Option<&'static Location<'static>>,
+    },
+
+    #[error("Lock {id} has not been acquired within {timeout:?}{}", holder_suffix(.holder))]
+    Timeout {
+        id: usize,
+        timeout: Duration,
+        holder: Option<&'static Location<'static>>,
+    },
+
+    #[error("Lock {id} is poisoned by a panic of its holder")]
+    Poisoned { id: usize },
+}
+
+/// Formats the holder location of the error
+fn holder_suffix(holder: &Option<&'static Location<'static>>) -> String {
+    holder.map_or_else(String::new, |holder| format!(" (acquired at {})", holder))
+}
+
+/// A handle of a change subscription, which is used to unsubscribe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(usize);
+
+/// A callback of a change subscription, which is called with the new generation
+type ChangeCallback = Box<dyn FnMut(u64) + Send>;
+
+/// A subscribed callback, which is shared with the notifying threads
+struct Subscriber {
+    /// The callback
+    callback: Mutex<ChangeCallback>,
+
+    /// The thread, which is currently calling the callback
+    caller: Mutex<Option<ThreadId>>,
+
+    /// The latest generation, which has not been passed to the callback yet
+    pending: Mutex<Option<u64>>,
+}
+
+impl Subscriber {
+    /// Calls the callback, unless it is already being called by the current thread.
+    ///
+    /// The notifying thread never waits for a callback, which is being called by another thread.
+    /// Instead, the generation is left pending, and that thread calls the callback once more
+    /// with the latest pending generation, before releasing the callback.
+    fn notify(&self, generation: u64) {
+        let current = thread::current().id();
+        let caller = || self.caller.lock().unwrap_or_else(PoisonError::into_inner);
+        let pending = || self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+        if *caller() == Some(current) {
+            return;
+        }
+        {
+            let mut pending = pending();
+            *pending = Some(pending.map_or(generation, |pending| pending.max(generation)));
+        }
+        loop {
+            let mut callback = match self.callback.try_lock() {
+                Ok(callback) => callback,
+                Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+                Err(TryLockError::WouldBlock) => return,
+            };
+            // The pending generation is not kept locked during the call
+            let next = || pending().take();
+            while let Some(generation) = next() {
+                *caller() = Some(current);
+                callback(generation);
+                *caller() = None;
+            }
+            drop(callback);
+
+            // A generation, which has been left pending after the last call,
+            // but before the release of the callback, would not be passed to it otherwise
+            if pending().is_none() {
+                return;
+            }
+        }
+    }
+}
+
+/// A lock, and its bookkeeping, which is shared between the clones of the lock
+struct Shared<T> {
+    /// The locked value
+    rw_lock: RwLock<T>,
+
+    /// Whether a write guard has been dropped during a panic
+    poisoned: AtomicBool,
+
+    /// The amount of the released write guards
+    generation: AtomicU64,
+
+    /// The callbacks, which are called after every release of a write guard
+    subscribers: Mutex<Vec<(SubscriptionId, Arc<Subscriber>)>>,
+
+    /// The location, at which the current write guard has been acquired
+    #[cfg(debug_assertions)]
+    holder: Mutex<Option<&'static Location<'static>>>,
+}
+
+pub struct WriteLock<T>(usize, Arc<Shared<T>>);
 
 pub struct ReadLock<T>(WriteLock<T>);
 
@@ -26,7 +144,11 @@ unsafe impl<T> Sync for WriteLock<T> {}
 unsafe impl<T> Send for Lock<T> {}
 unsafe impl<T> Sync for Lock<T> {}
 
-pub struct Guard<'a, T>(RwLockWriteGuard<'a, T>);
+/// A write guard of the lock.
+///
+/// Releasing the guard advances the generation of the lock,
+/// and then notifies the change subscribers.
+pub struct Guard<'a, T>(ManuallyDrop<RwLockWriteGuard<'a, T>>, &'a Shared<T>);
 
 pub struct ReadGuard<'a, T>(RwLockReadGuard<'a, T>);
 
@@ -34,7 +156,14 @@ impl<T> From<T> for WriteLock<T> {
     fn from(x: T) -> Self {
         Self(
             GLOBAL_LOCK_COUNTER.fetch_add(1, Relaxed),
-            Arc::new(RwLock::new(x)),
+            Arc::new(Shared {
+                rw_lock: RwLock::new(x),
+                poisoned: AtomicBool::new(false),
+                generation: AtomicU64::new(0),
+                subscribers: Default::default(),
+                #[cfg(debug_assertions)]
+                holder: Default::default(),
+            }),
         )
     }
 }
@@ -69,14 +198,219 @@ impl<T> WriteLock<T> {
         Lock(self.clone())
     }
 
+    /// Acquires the read access, blocking the thread.
+    ///
+    /// Panics, if the lock is poisoned.
+    #[track_caller]
     pub fn lock(&self) -> ReadGuard<'_, T> {
-        let Self(_, rw_lock) = self;
-        ReadGuard(rw_lock.read().unwrap())
+        let Self(_, shared) = self;
+        self.check_poison()
+            .unwrap_or_else(|error| panic!("{}", error));
+        self.read_guard(shared.rw_lock.read())
     }
 
+    /// Acquires the write access, blocking the thread.
+    ///
+    /// Panics, if the lock is poisoned.
+    #[track_caller]
     pub fn lock_mut(&self) -> Guard<'_, T> {
-        let Self(_, rw_lock) = self;
-        Guard(rw_lock.write().unwrap())
+        let Self(_, shared) = self;
+        self.check_poison()
+            .unwrap_or_else(|error| panic!("{}", error));
+        self.write_guard(shared.rw_lock.write())
+    }
+
+    /// Acquires the read access, if it is possible without blocking.
+    #[track_caller]
+    pub fn try_lock(&self) -> Result<ReadGuard<'_, T>, LockError> {
+        let Self(_, shared) = self;
+        self.check_poison()?;
+        match shared.rw_lock.try_read() {
+            Ok(guard) => Ok(self.read_guard(Ok(guard))),
+            Err(TryLockError::Poisoned(poisoned)) => Ok(self.read_guard(Err(poisoned))),
+            Err(TryLockError::WouldBlock) => Err(self.would_block()),
+        }
+    }
+
+    /// Acquires the write access, if it is possible without blocking.
+    #[track_caller]
+    pub fn try_lock_mut(&self) -> Result<Guard<'_, T>, LockError> {
+        let Self(_, shared) = self;
+        self.check_poison()?;
+        match shared.rw_lock.try_write() {
+            Ok(guard) => Ok(self.write_guard(Ok(guard))),
+            Err(TryLockError::Poisoned(poisoned)) => Ok(self.write_guard(Err(poisoned))),
+            Err(TryLockError::WouldBlock) => Err(self.would_block()),
+        }
+    }
+
+    /// Acquires the read access, blocking the thread for at most the timeout.
+    #[track_caller]
+    pub fn lock_timeout(&self, timeout: Duration) -> Result<ReadGuard<'_, T>, LockError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.try_lock() {
+                Err(LockError::WouldBlock { id, holder }) if Instant::now() >= deadline => {
+                    break Err(LockError::Timeout {
+                        id,
+                        timeout,
+                        holder,
+                    })
+                }
+                Err(LockError::WouldBlock { .. }) => thread::sleep(TIMED_LOCK_BACKOFF),
+                result => break result,
+            }
+        }
+    }
+
+    /// Acquires the write access, blocking the thread for at most the timeout.
+    #[track_caller]
+    pub fn lock_mut_timeout(&self, timeout: Duration) -> Result<Guard<'_, T>, LockError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.try_lock_mut() {
+                Err(LockError::WouldBlock { id, holder }) if Instant::now() >= deadline => {
+                    break Err(LockError::Timeout {
+                        id,
+                        timeout,
+                        holder,
+                    })
+                }
+                Err(LockError::WouldBlock { .. }) => thread::sleep(TIMED_LOCK_BACKOFF),
+                result => break result,
+            }
+        }
+    }
+
+    /// Checks, whether a holder of the write access has panicked.
+    pub fn is_poisoned(&self) -> bool {
+        let Self(_, shared) = self;
+        shared.poisoned.load(Relaxed)
+    }
+
+    /// Gets the generation of the value, i.e. the amount of the released write guards.
+    ///
+    /// Consumers may remember the generation, and compare it later
+    /// to check cheaply, whether the value might have been changed.
+    pub fn generation(&self) -> u64 {
+        let Self(_, shared) = self;
+        shared.generation.load(Relaxed)
+    }
+
+    /// Checks, whether a write guard has been released since the generation.
+    pub fn changed_since(&self, generation: u64) -> bool {
+        self.generation() != generation
+    }
+
+    /// Subscribes the callback to the changes of the value.
+    ///
+    /// The callback is called with the new generation after every release of a write guard,
+    /// on the releasing thread. The callbacks are not called, if the guard is released during a panic.
+    /// The releases, which happen while the callback is being called by another thread,
+    /// are not waited for, but notified once afterwards with the latest generation by that thread.
+    ///
+    /// The callback may lock the value (for writing as well), subscribe, and unsubscribe.
+    /// The changes, made by the callback, are notified to the other subscribers,
+    /// but not to the callback itself. A callback, which is unsubscribed
+    /// during a notification, may still be called by this notification.
+    pub fn subscribe(&self, callback: impl FnMut(u64) + Send + 'static) -> SubscriptionId {
+        let Self(_, shared) = self;
+        let id = SubscriptionId(GLOBAL_SUBSCRIPTION_COUNTER.fetch_add(1, Relaxed));
+        shared
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((
+                id,
+                Arc::new(Subscriber {
+                    callback: Mutex::new(Box::new(callback)),
+                    caller: Default::default(),
+                    pending: Default::default(),
+                }),
+            ));
+        id
+    }
+
+    /// Unsubscribes the callback, returning whether it was subscribed.
+    pub fn unsubscribe(&self, subscription: SubscriptionId) -> bool {
+        let Self(_, shared) = self;
+        let mut subscribers = shared
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let count = subscribers.len();
+        subscribers.retain(|(id, _)| id != &subscription);
+        subscribers.len() != count
+    }
+
+    /// Acquires the write access regardless of the poisoning, and clears the poisoning.
+    ///
+    /// The caller is expected to restore the invariants of the value,
+    /// which might have been broken by the panicked holder.
+    #[track_caller]
+    pub fn recover(&self) -> Guard<'_, T> {
+        let Self(id, shared) = self;
+        let guard = self.write_guard(shared.rw_lock.write());
+        if shared.poisoned.swap(false, Relaxed) {
+            warn!("Recovered poisoned lock {}", id);
+        }
+        guard
+    }
+
+    /// Fails, if the lock is poisoned
+    fn check_poison(&self) -> Result<(), LockError> {
+        match self.is_poisoned() {
+            true => Err(LockError::Poisoned { id: self.id() }),
+            false => Ok(()),
+        }
+    }
+
+    /// Wraps the acquired read guard.
+    ///
+    /// The poisoning of the underlying lock is ignored,
+    /// as the lock tracks the panics of its own guards.
+    #[track_caller]
+    fn read_guard<'a>(
+        &self,
+        guard: Result<RwLockReadGuard<'a, T>, PoisonError<RwLockReadGuard<'a, T>>>,
+    ) -> ReadGuard<'a, T> {
+        trace!("Lock {} read at {}", self.id(), Location::caller());
+        ReadGuard(guard.unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Wraps the acquired write guard, remembering its location in debug builds.
+    ///
+    /// The poisoning of the underlying lock is ignored,
+    /// as the lock tracks the panics of its own guards.
+    #[track_caller]
+    fn write_guard<'a>(
+        &'a self,
+        guard: Result<RwLockWriteGuard<'a, T>, PoisonError<RwLockWriteGuard<'a, T>>>,
+    ) -> Guard<'a, T> {
+        let Self(id, shared) = self;
+        trace!("Lock {} written at {}", id, Location::caller());
+        #[cfg(debug_assertions)]
+        {
+            *shared.holder.lock().unwrap_or_else(PoisonError::into_inner) =
+                Some(Location::caller());
+        }
+        Guard(
+            ManuallyDrop::new(guard.unwrap_or_else(PoisonError::into_inner)),
+            shared,
+        )
+    }
+
+    /// Creates the error of a blocked acquisition
+    fn would_block(&self) -> LockError {
+        let Self(id, _shared) = self;
+        #[cfg(debug_assertions)]
+        let holder = *_shared
+            .holder
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        #[cfg(not(debug_assertions))]
+        let holder = None;
+        LockError::WouldBlock { id: *id, holder }
     }
 }
 
@@ -108,10 +442,48 @@ impl<T> ReadLock<T> {
         inner.id()
     }
 
+    #[track_caller]
     pub fn lock(&self) -> ReadGuard<'_, T> {
         let Self(inner) = self;
         inner.lock()
     }
+
+    #[track_caller]
+    pub fn try_lock(&self) -> Result<ReadGuard<'_, T>, LockError> {
+        let Self(inner) = self;
+        inner.try_lock()
+    }
+
+    #[track_caller]
+    pub fn lock_timeout(&self, timeout: Duration) -> Result<ReadGuard<'_, T>, LockError> {
+        let Self(inner) = self;
+        inner.lock_timeout(timeout)
+    }
+
+    pub fn is_poisoned(&self) -> bool {
+        let Self(inner) = self;
+        inner.is_poisoned()
+    }
+
+    pub fn generation(&self) -> u64 {
+        let Self(inner) = self;
+        inner.generation()
+    }
+
+    pub fn changed_since(&self, generation: u64) -> bool {
+        let Self(inner) = self;
+        inner.changed_since(generation)
+    }
+
+    pub fn subscribe(&self, callback: impl FnMut(u64) + Send + 'static) -> SubscriptionId {
+        let Self(inner) = self;
+        inner.subscribe(callback)
+    }
+
+    pub fn unsubscribe(&self, subscription: SubscriptionId) -> bool {
+        let Self(inner) = self;
+        inner.unsubscribe(subscription)
+    }
 }
 
 impl<T> From<T> for Lock<T> {
@@ -135,28 +507,119 @@ impl<T> Lock<T> {
         inner.id()
     }
 
+    #[track_caller]
     pub fn lock(&self) -> ReadGuard<'_, T> {
         let Self(inner) = self;
         inner.lock()
     }
 
+    #[track_caller]
     pub fn lock_mut(&mut self) -> Guard<'_, T> {
         let Self(inner) = self;
         inner.lock_mut()
     }
+
+    #[track_caller]
+    pub fn try_lock(&self) -> Result<ReadGuard<'_, T>, LockError> {
+        let Self(inner) = self;
+        inner.try_lock()
+    }
+
+    #[track_caller]
+    pub fn try_lock_mut(&mut self) -> Result<Guard<'_, T>, LockError> {
+        let Self(inner) = self;
+        inner.try_lock_mut()
+    }
+
+    #[track_caller]
+    pub fn lock_timeout(&self, timeout: Duration) -> Result<ReadGuard<'_, T>, LockError> {
+        let Self(inner) = self;
+        inner.lock_timeout(timeout)
+    }
+
+    #[track_caller]
+    pub fn lock_mut_timeout(&mut self, timeout: Duration) -> Result<Guard<'_, T>, LockError> {
+        let Self(inner) = self;
+        inner.lock_mut_timeout(timeout)
+    }
+
+    pub fn is_poisoned(&self) -> bool {
+        let Self(inner) = self;
+        inner.is_poisoned()
+    }
+
+    pub fn generation(&self) -> u64 {
+        let Self(inner) = self;
+        inner.generation()
+    }
+
+    pub fn changed_since(&self, generation: u64) -> bool {
+        let Self(inner) = self;
+        inner.changed_since(generation)
+    }
+
+    pub fn subscribe(&self, callback: impl FnMut(u64) + Send + 'static) -> SubscriptionId {
+        let Self(inner) = self;
+        inner.subscribe(callback)
+    }
+
+    pub fn unsubscribe(&self, subscription: SubscriptionId) -> bool {
+        let Self(inner) = self;
+        inner.unsubscribe(subscription)
+    }
+
+    #[track_caller]
+    pub fn recover(&mut self) -> Guard<'_, T> {
+        let Self(inner) = self;
+        inner.recover()
+    }
 }
 
 impl<T> Deref for Guard<'_, T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &*self.0
+        &**self.0
     }
 }
 
 impl<T> DerefMut for Guard<'_, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut *self.0
+        &mut **self.0
+    }
+}
+
+/// A write guard, which is dropped during a panic, poisons the lock.
+impl<T> Drop for Guard<'_, T> {
+    fn drop(&mut self) {
+        let shared = self.1;
+        let panicking = thread::panicking();
+        if panicking {
+            shared.poisoned.store(true, Relaxed)
+        }
+        #[cfg(debug_assertions)]
+        {
+            *shared.holder.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        }
+        let generation = shared.generation.fetch_add(1, Relaxed) + 1;
+
+        // SAFETY: the guard is never accessed after the release.
+        // The subscribers are notified after the release, so they may lock the value
+        unsafe { ManuallyDrop::drop(&mut self.0) };
+        if !panicking {
+            // The subscribers are notified outside of the subscriber list lock,
+            // so they may subscribe, unsubscribe, and release their own write guards
+            let subscribers = shared
+                .subscribers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .map(|(_, subscriber)| subscriber.clone())
+                .collect::<Vec<_>>();
+            subscribers
+                .iter()
+                .for_each(|subscriber| subscriber.notify(generation))
+        }
     }
 }
 
@@ -0,0 +1,122 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkState, EngineState, Mechanisms},
+    prelude::*,
+    standard_runtime::{EventReader, Events, EventsMechanism, StandardMechanism},
+    util::sync::WriteLock,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CollisionStarted(u32);
+
+#[derive(ClockworkState, Default)]
+struct State {
+    collisions: Events<CollisionStarted>,
+}
+
+/// Sends a collision upon every tick
+struct Sender(u32);
+
+impl StandardMechanism<State> for Sender {
+    fn tick(&mut self, state: &mut EngineState<State>) {
+        self.0 += 1;
+        let collision = CollisionStarted(self.0);
+        state
+            .start_mutate()
+            .get_mut(|collisions: &mut Events<CollisionStarted>| collisions.send(collision))
+            .finish()
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        Some(vec![StandardEvent::Tick])
+    }
+}
+
+/// Reads the collisions upon every draw call
+struct Reader(EventReader<CollisionStarted>, WriteLock<Vec<u32>>);
+
+impl StandardMechanism<State> for Reader {
+    fn draw(&mut self, state: &mut EngineState<State>) {
+        let Self(reader, log) = self;
+        state
+            .start_access()
+            .get(|collisions: &Events<CollisionStarted>| {
+                log.lock_mut()
+                    .extend(reader.read(collisions).map(|collision| collision.0))
+            })
+            .finish()
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        Some(vec![StandardEvent::Draw])
+    }
+}
+
+#[test]
+fn readers_see_every_message_once() {
+    let mut events = Events::default();
+    let mut early = EventReader::default();
+    events.send(1);
+    let mut late = events.reader();
+    events.send_batch(vec![2, 3]);
+    assert_eq!(early.len(&events), 3);
+    assert_eq!(late.read(&events).copied().collect::<Vec<_>>(), vec![2, 3]);
+    events.update();
+    events.send(4);
+    assert_eq!(
+        early.read(&events).copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(late.read(&events).copied().collect::<Vec<_>>(), vec![4]);
+    assert!(early.is_empty(&events));
+}
+
+#[test]
+fn messages_survive_one_update() {
+    let mut events = Events::default();
+    let mut reader = EventReader::default();
+    events.send(1);
+    events.update();
+    events.send(2);
+    events.update();
+    assert_eq!(events.len(), 1);
+    assert_eq!(reader.read(&events).copied().collect::<Vec<_>>(), vec![2]);
+    events.clear();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn mechanisms_communicate_through_channels() {
+    let log = WriteLock::from(Vec::new());
+    let log_in_loop = log.clone();
+    Clockwork::<State, StandardEvent>::builder()
+        .state(State::default())
+        .main_loop(
+            move |mut state, mut mechanisms: Mechanisms<State, StandardEvent>| {
+                let cycles_and_draw = |cycles: usize| {
+                    [StandardEvent::Tick, StandardEvent::LateTick]
+                        .iter()
+                        .cycle()
+                        .take(2 * cycles)
+                        .chain(&[StandardEvent::Draw])
+                        .copied()
+                        .collect::<Vec<_>>()
+                };
+                // A message is readable during the next tick cycle
+                cycles_and_draw(1)
+                    .into_iter()
+                    .for_each(|event| mechanisms.clink_event(&mut state, event));
+                assert_eq!(*log_in_loop.lock(), vec![1]);
+                // A message, which is not read during the next tick cycle, is dropped
+                cycles_and_draw(2)
+                    .into_iter()
+                    .for_each(|event| mechanisms.clink_event(&mut state, event));
+                assert_eq!(*log_in_loop.lock(), vec![1, 3]);
+            },
+        )
+        .add_standard_mechanism(Sender(0))
+        .add_standard_mechanism(Reader(Default::default(), log))
+        .add_standard_mechanism(EventsMechanism::<CollisionStarted>::default())
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
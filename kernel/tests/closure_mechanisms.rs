@@ -0,0 +1,164 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkState, EngineState, Mechanism, MechanismExt, Mechanisms},
+    clockwork::ClockworkBuilder,
+    prelude::*,
+    standard_runtime::{standard_mechanism, StandardMechanism, StandardRuntimeStatistics},
+    util::sync::WriteLock,
+};
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct State {
+    ticks: u32,
+    draws: u32,
+
+    /// The tick count of the main loop
+    ticks_total: u64,
+}
+impl ClockworkState for State {}
+
+/// Only counts the ticks
+impl StandardRuntimeStatistics for State {
+    type Frequency = f32;
+    type Count = u64;
+
+    fn duration_to_freq(duration: Duration) -> f32 {
+        1f32 / duration.as_secs_f32()
+    }
+
+    fn current_tick_delta(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn desired_avg_tick_delta(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn current_draw_delta(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn desired_min_draw_period(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn init_time(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn ticks_total(&self) -> u64 {
+        self.ticks_total
+    }
+
+    fn frames_total(&self) -> u64 {
+        0
+    }
+}
+
+/// Only defines the tick handler
+struct TickCounter;
+
+#[standard_mechanism]
+impl StandardMechanism<State> for TickCounter {
+    fn tick(&mut self, state: &mut EngineState<State>) {
+        state
+            .start_mutate()
+            .get_mut(|state: &mut State| state.ticks += 1)
+            .finish()
+    }
+}
+
+struct Listener(WriteLock<Vec<StandardEvent>>);
+
+impl Mechanism<State, StandardEvent> for Listener {
+    fn clink(&mut self, _: &mut EngineState<State>, event: StandardEvent) {
+        self.0.lock_mut().push(event)
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        None
+    }
+}
+
+fn run(builder: ClockworkBuilder<State, StandardEvent>, events: &[StandardEvent]) -> (u32, u32) {
+    let counts = WriteLock::from((0, 0));
+    let (events, result) = (events.to_vec(), counts.clone());
+    builder
+        .state(State::default())
+        .main_loop(move |mut state, mut mechanisms: Mechanisms<_, _>| {
+            events.into_iter().for_each(|event| {
+                if event == StandardEvent::Tick {
+                    state
+                        .start_mutate()
+                        .get_mut(|state: &mut State| state.ticks_total += 1)
+                        .finish()
+                }
+                mechanisms.clink_event(&mut state, event)
+            });
+            *result.lock_mut() = state
+                .start_access()
+                .get(|state: &State| (state.ticks, state.draws))
+                .finish()
+        })
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let counts = *counts.lock();
+    counts
+}
+
+#[test]
+fn standard_mechanisms_only_define_used_handlers() {
+    use StandardEvent::*;
+    let counts = run(
+        Clockwork::builder().add_standard_mechanism(TickCounter),
+        &[Initialization, Tick, Draw, Tick, Draw, Termination],
+    );
+    assert_eq!(counts, (2, 0));
+}
+
+#[test]
+fn closures_are_clinked_upon_their_events() {
+    use StandardEvent::*;
+    let counts = run(
+        Clockwork::builder()
+            .on(Tick, |state| {
+                state
+                    .start_mutate()
+                    .get_mut(|state: &mut State| state.ticks += 1)
+                    .finish()
+            })
+            .on_event(Draw, |state, event| {
+                assert_eq!(event, Draw);
+                state
+                    .start_mutate()
+                    .get_mut(|state: &mut State| state.draws += 1)
+                    .finish()
+            }),
+        &[Tick, Draw, Tick, Termination],
+    );
+    assert_eq!(counts, (2, 1));
+}
+
+#[test]
+fn combinators_change_the_seen_events() {
+    use StandardEvent::*;
+    let (filtered, mapped, throttled) = (
+        WriteLock::from(Vec::new()),
+        WriteLock::from(Vec::new()),
+        WriteLock::from(Vec::new()),
+    );
+    run(
+        Clockwork::builder()
+            .add_mechanism(Listener(filtered.clone()).filter(|event| *event != Tick))
+            .add_mechanism(Listener(mapped.clone()).map(|event| match event {
+                Draw => Tick,
+                event => event,
+            }))
+            .add_mechanism(Listener(throttled.clone()).throttle::<State>(2)),
+        &[Tick, Draw, Tick, Draw, Tick],
+    );
+    assert_eq!(*filtered.lock(), vec![Draw, Draw]);
+    assert_eq!(*mapped.lock(), vec![Tick; 5]);
+    assert_eq!(*throttled.lock(), vec![Tick, Tick]);
+}
@@ -0,0 +1,47 @@
+//! Helpers, which are shared between the tests.
+//!
+//! Every test only uses a part of the helpers.
+#![allow(dead_code)]
+
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkEvent, ClockworkState, EngineState, Mechanisms},
+    clockwork::{ClockworkBuilder, ClockworkBuilderError},
+    math::Matrix,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// Builds the engine, whose main loop calls the closure once,
+/// and returns the result of the closure.
+pub fn run<S, E, R>(
+    builder: ClockworkBuilder<S, E>,
+    main_loop: impl FnOnce(&mut EngineState<S>, &mut Mechanisms<S, E>) -> R + 'static,
+) -> Result<R, ClockworkBuilderError>
+where
+    S: ClockworkState,
+    E: ClockworkEvent,
+    R: 'static,
+{
+    let result = Rc::new(RefCell::new(None));
+    let output = result.clone();
+    builder
+        .main_loop(move |mut state, mut mechanisms| {
+            *output.borrow_mut() = Some(main_loop(&mut state, &mut mechanisms))
+        })
+        .build()?
+        .set_the_clock();
+    let result = result.borrow_mut().take();
+    Ok(result.expect("The main loop returns the result"))
+}
+
+/// Checks, whether the matrices are equal up to the float precision
+pub fn assert_approx_eq<const N: usize, const M: usize>(
+    actual: Matrix<f32, N, M>,
+    expected: Matrix<f32, N, M>,
+) {
+    assert!(
+        (actual - expected).norm() < 1e-5,
+        "{:?} != {:?}",
+        actual,
+        expected
+    )
+}
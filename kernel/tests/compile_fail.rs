@@ -0,0 +1,5 @@
+/// Checks, that the misuses of the substates are rejected at compile time
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,18 @@
+use spc_clockwork_kernel::abstract_runtime::ClockworkState;
+
+struct Physics;
+impl ClockworkState for Physics {}
+
+#[derive(ClockworkState)]
+struct World {
+    physics: Physics,
+}
+
+#[derive(ClockworkState)]
+struct State {
+    physics: Physics,
+    #[clockwork(substates(Physics))]
+    world: World,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use spc_clockwork_kernel::abstract_runtime::{
+    ClockworkState, DynamicState, DynamicSubstate, EngineState,
+};
+
+struct Score(u32);
+impl ClockworkState for Score {}
+impl DynamicSubstate for Score {}
+
+#[derive(ClockworkState)]
+struct State {
+    #[clockwork(substates(Score))]
+    dynamic: DynamicState,
+}
+
+fn use_after_free(state: &mut EngineState<State>) {
+    state
+        .start_mutate()
+        .get_disjoint_mut(|dynamic: &mut DynamicState, score: &mut Score| {
+            dynamic.remove::<Score>();
+            score.0 += 1
+        })
+        .finish()
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use spc_clockwork_kernel::abstract_runtime::{ClockworkState, EngineState};
+
+struct Audio(u32);
+impl ClockworkState for Audio {}
+
+#[derive(ClockworkState)]
+struct Sound {
+    audio: Audio,
+}
+
+#[derive(ClockworkState)]
+struct State {
+    #[clockwork(substates(Audio))]
+    sound: Sound,
+}
+
+fn overlap(state: &mut EngineState<State>) {
+    state
+        .start_mutate()
+        .get_disjoint_mut(|_: &mut Sound, _: &mut Audio| ())
+        .finish()
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use spc_clockwork_kernel::abstract_runtime::{ClockworkState, SubstateAccess};
+
+struct Physics;
+impl ClockworkState for Physics {}
+
+#[derive(ClockworkState)]
+struct State {
+    physics: Physics,
+}
+
+fn main() {
+    SubstateAccess::<State>::default()
+        .read::<State>()
+        .write::<Physics>();
+}
@@ -0,0 +1,53 @@
+use spc_clockwork_kernel::abstract_runtime::{ClockworkState, Substate};
+
+#[derive(Default)]
+struct Physics(u32);
+impl ClockworkState for Physics {}
+
+#[derive(Default)]
+struct Gravity(f32);
+impl ClockworkState for Gravity {}
+
+#[derive(Default)]
+struct Input(Vec<char>);
+impl ClockworkState for Input {}
+
+#[derive(ClockworkState, Default)]
+struct World {
+    physics: Physics,
+    gravity: Gravity,
+}
+
+#[derive(ClockworkState, Default)]
+struct Game<T>
+where
+    T: ClockworkState + Default,
+{
+    #[clockwork(substates(Physics, Gravity))]
+    world: World,
+    input: Input,
+    #[clockwork(skip)]
+    custom: T,
+    #[clockwork(skip)]
+    frames: u64,
+}
+
+fn increment<S: Substate<Physics>>(state: &mut S) {
+    state.substate_mut(|Physics(counter)| *counter += 1)
+}
+
+#[test]
+fn fields_and_nested_substates_are_reachable() {
+    let mut game = Game::<Input>::default();
+    increment(&mut game);
+    increment(&mut game.world);
+    game.substate_mut(|Gravity(gravity)| *gravity = -9.8);
+    game.substate_mut(|Input(keys): &mut Input| keys.push('w'));
+    game.substate_mut(|World { physics, .. }: &mut World| physics.0 += 1);
+    game.frames += 1;
+
+    assert_eq!(game.substate(|Physics(counter)| *counter), 3);
+    assert_eq!(game.substate(|Gravity(gravity)| *gravity), -9.8);
+    assert_eq!(game.input.0, vec!['w']);
+    assert!(game.custom.0.is_empty());
+}
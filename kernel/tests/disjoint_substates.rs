@@ -0,0 +1,73 @@
+mod common;
+
+use common::run;
+use spc_clockwork_kernel::abstract_runtime::ClockworkState;
+use spc_clockwork_kernel::{clockwork::ClockworkBuilder, prelude::*};
+
+#[derive(Default, Debug, PartialEq)]
+struct Physics(u32);
+impl ClockworkState for Physics {}
+
+#[derive(Default, Debug, PartialEq)]
+struct Ecs(u32);
+impl ClockworkState for Ecs {}
+
+#[derive(Default, Debug, PartialEq)]
+struct Audio(u32);
+impl ClockworkState for Audio {}
+
+/// A wrapper, through which the audio is delegated
+#[derive(ClockworkState, Default)]
+struct Sound {
+    audio: Audio,
+}
+
+#[derive(ClockworkState, Default)]
+struct State {
+    physics: Physics,
+    ecs: Ecs,
+    #[clockwork(substates(Audio))]
+    sound: Sound,
+}
+
+fn builder() -> ClockworkBuilder<State, StandardEvent> {
+    Clockwork::builder().state(State::default())
+}
+
+#[test]
+fn distinct_substates_are_borrowed_together() {
+    run(builder(), |state, _| {
+        let sum = state
+            .start_mutate()
+            .get_disjoint_mut(|physics: &mut Physics, ecs: &mut Ecs, sound: &mut Sound| {
+                physics.0 = 1;
+                ecs.0 = 2;
+                sound.audio.0 = 3;
+                physics.0 + ecs.0 + sound.audio.0
+            })
+            .finish();
+        assert_eq!(sum, 6);
+        state
+            .start_mutate()
+            .get_disjoint_mut(|ecs: &mut Ecs, physics: &mut Physics| ecs.0 += physics.0)
+            .finish();
+        state
+            .start_access()
+            .get(|ecs: &Ecs| assert_eq!(ecs, &Ecs(3)))
+            .then_get(|_, audio: &Audio| assert_eq!(audio, &Audio(3)))
+            .finish()
+    })
+    .unwrap()
+}
+
+#[test]
+#[should_panic(expected = "twice")]
+fn repeated_substates_are_rejected() {
+    run(builder(), |state, _| {
+        state
+            .start_mutate()
+            .get_disjoint_mut(|_: &mut Physics, _: &mut Physics| ())
+            .finish()
+    })
+    .unwrap()
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::run;
+use spc_clockwork_kernel::abstract_runtime::{
+    ClockworkState, DynamicState, DynamicSubstate, MissingSubstateError,
+};
+use spc_clockwork_kernel::{clockwork::ClockworkBuilder, prelude::*};
+
+#[derive(Debug, PartialEq)]
+struct Score(u32);
+impl ClockworkState for Score {}
+impl DynamicSubstate for Score {}
+
+#[derive(Debug, PartialEq)]
+struct Script(&'static str);
+impl ClockworkState for Script {}
+impl DynamicSubstate for Script {}
+
+#[derive(ClockworkState)]
+struct State {
+    #[clockwork(substates(Score, Script))]
+    dynamic: DynamicState,
+}
+
+fn builder() -> ClockworkBuilder<State, StandardEvent> {
+    Clockwork::builder().state(State {
+        dynamic: DynamicState::default().with(Score(1)),
+    })
+}
+
+#[test]
+fn dynamic_substates_are_inserted_and_removed() {
+    let mut dynamic = DynamicState::default();
+    assert!(dynamic.is_empty());
+    assert_eq!(dynamic.insert(Score(1)), None);
+    assert_eq!(dynamic.insert(Score(2)), Some(Score(1)));
+    assert!(dynamic.contains::<Score>());
+    assert!(!dynamic.contains::<Script>());
+    assert_eq!(
+        dynamic.get::<Script>(),
+        Err(MissingSubstateError(std::any::type_name::<Script>()))
+    );
+    assert_eq!(dynamic.remove::<Score>(), Some(Score(2)));
+    assert_eq!(dynamic.len(), 0);
+}
+
+#[test]
+fn dynamic_substates_are_delegated_to() {
+    run(builder(), |state, _| {
+        state
+            .start_mutate()
+            .get_mut(|dynamic: &mut DynamicState| dynamic.insert(Script("jump")))
+            .finish();
+        state
+            .start_mutate()
+            .get_mut(|score: &mut Score| score.0 += 1)
+            .finish();
+        state
+            .start_access()
+            .get(|score: &Score| assert_eq!(score, &Score(2)))
+            .then_get(|_, script: &Script| assert_eq!(script, &Script("jump")))
+            .finish()
+    })
+    .unwrap()
+}
+
+#[test]
+#[should_panic(expected = "is missing in the dynamic state")]
+fn missing_dynamic_substates_panic() {
+    run(builder(), |state, _| {
+        state.start_access().get(|_: &Script| ()).finish()
+    })
+    .unwrap()
+}
@@ -0,0 +1,78 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkEvent, ClockworkState, EngineState, Mechanism, Mechanisms},
+    prelude::*,
+    util::sync::WriteLock,
+};
+
+struct State;
+impl ClockworkState for State {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Tick,
+    Collision { impulse: f32 },
+    Message(String),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+enum EventKind {
+    Tick,
+    Collision,
+    Message,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = EventKind;
+
+    fn kind(&self) -> EventKind {
+        match self {
+            Event::Tick => EventKind::Tick,
+            Event::Collision { .. } => EventKind::Collision,
+            Event::Message(_) => EventKind::Message,
+        }
+    }
+}
+
+struct Listener(EventKind, WriteLock<Vec<Event>>);
+
+impl Mechanism<State, Event> for Listener {
+    fn clink(&mut self, _: &mut EngineState<State>, event: Event) {
+        self.1.lock_mut().push(event)
+    }
+
+    fn handled_events(&self) -> Option<Vec<EventKind>> {
+        Some(vec![self.0.clone()])
+    }
+}
+
+#[test]
+fn events_are_routed_by_kind_with_payload() {
+    let collisions = WriteLock::from(Vec::new());
+    let messages = WriteLock::from(Vec::new());
+    Clockwork::<State, Event>::builder()
+        .state(State)
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            vec![
+                Event::Tick,
+                Event::Collision { impulse: 0.5 },
+                Event::Message("hello".into()),
+                Event::Collision { impulse: 2.0 },
+            ]
+            .into_iter()
+            .for_each(|event| mechanisms.clink_event(&mut state, event))
+        })
+        .add_mechanism(Listener(EventKind::Collision, collisions.clone()))
+        .add_mechanism(Listener(EventKind::Message, messages.clone()))
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let (collisions, messages) = (collisions.lock().clone(), messages.lock().clone());
+    assert_eq!(
+        collisions,
+        vec![
+            Event::Collision { impulse: 0.5 },
+            Event::Collision { impulse: 2.0 }
+        ]
+    );
+    assert_eq!(messages, vec![Event::Message("hello".into())]);
+}
@@ -0,0 +1,117 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkEvent, ClockworkState, EngineState, ErrorPolicy, FallibleMechanism, Mechanism,
+        MechanismError, MechanismOrdering, Mechanisms,
+    },
+    prelude::*,
+    util::sync::WriteLock,
+};
+
+struct State;
+impl ClockworkState for State {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    Tick,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+type Log = WriteLock<Vec<&'static str>>;
+
+struct Failing(&'static str, Log);
+
+impl FallibleMechanism<State, Event> for Failing {
+    fn try_clink(&mut self, _: &mut EngineState<State>, _: Event) -> Result<(), MechanismError> {
+        self.1.lock_mut().push(self.0);
+        Err(format!("{} failed", self.0).into())
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+}
+
+struct Panicking(Log);
+
+impl Mechanism<State, Event> for Panicking {
+    fn clink(&mut self, _: &mut EngineState<State>, _: Event) {
+        self.0.lock_mut().push("panicking");
+        panic!("Broken mechanism")
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        Some(vec![Event::Tick])
+    }
+}
+
+fn run(policy: ErrorPolicy) -> (Vec<&'static str>, bool) {
+    let log = Log::default();
+    let termination_requested = WriteLock::from(false);
+    let termination_requested_in_loop = termination_requested.clone();
+    Clockwork::<State, Event>::builder()
+        .state(State)
+        .main_loop(move |mut state, mut mechanisms: Mechanisms<_, _>| {
+            (0..2).for_each(|_| mechanisms.clink_event(&mut state, Event::Tick));
+            *termination_requested_in_loop.lock_mut() = mechanisms.is_termination_requested();
+        })
+        .add_ordered_fallible_mechanism(
+            Failing("labeled", log.clone()),
+            MechanismOrdering::labeled("labeled"),
+        )
+        .add_fallible_mechanism(Failing("unlabeled", log.clone()))
+        .add_mechanism(Panicking(log.clone()))
+        .error_policy("labeled", policy)
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let log = log.lock().clone();
+    let termination_requested = *termination_requested.lock();
+    (log, termination_requested)
+}
+
+#[test]
+fn failures_are_logged_by_default() {
+    assert_eq!(
+        run(ErrorPolicy::Log),
+        (
+            vec![
+                "panicking",
+                "labeled",
+                "unlabeled",
+                "panicking",
+                "labeled",
+                "unlabeled"
+            ],
+            false
+        )
+    )
+}
+
+#[test]
+fn failed_mechanisms_are_disabled() {
+    assert_eq!(
+        run(ErrorPolicy::Disable),
+        (
+            vec![
+                "panicking",
+                "labeled",
+                "unlabeled",
+                "panicking",
+                "unlabeled"
+            ],
+            false
+        )
+    )
+}
+
+#[test]
+fn failed_mechanisms_request_termination() {
+    assert!(run(ErrorPolicy::Terminate).1)
+}
@@ -0,0 +1,85 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkEvent, ClockworkState, EngineState, Mechanism, Mechanisms},
+    prelude::*,
+    util::sync::WriteLock,
+};
+
+struct State;
+impl ClockworkState for State {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    Start,
+    Left,
+    Right,
+    Leaf,
+    Loop,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+struct Cascade(WriteLock<Vec<Event>>);
+
+impl Mechanism<State, Event> for Cascade {
+    fn clink(&mut self, state: &mut EngineState<State>, event: Event) {
+        self.0.lock_mut().push(event);
+        match event {
+            Event::Start => {
+                state.emit_event(Event::Left);
+                state.emit_event(Event::Right);
+            }
+            Event::Left | Event::Right => state.emit_event(Event::Leaf),
+            Event::Leaf => {}
+            Event::Loop => state.emit_event(Event::Loop),
+        }
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+}
+
+fn run(event: Event, max_cascade_depth: usize) -> Vec<Event> {
+    let log = WriteLock::from(Vec::new());
+    Clockwork::<State, Event>::builder()
+        .state(State)
+        .main_loop(move |mut state, mut mechanisms: Mechanisms<_, _>| {
+            mechanisms.clink_event(&mut state, event)
+        })
+        .add_mechanism(Cascade(log.clone()))
+        .max_cascade_depth(max_cascade_depth)
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let result = log.lock().clone();
+    result
+}
+
+#[test]
+fn follow_up_events_are_handled_in_fifo_order() {
+    assert_eq!(
+        run(Event::Start, 16),
+        vec![
+            Event::Start,
+            Event::Left,
+            Event::Right,
+            Event::Leaf,
+            Event::Leaf
+        ]
+    )
+}
+
+#[test]
+fn cascades_are_limited_by_depth() {
+    assert_eq!(run(Event::Loop, 3), vec![Event::Loop; 4]);
+    assert_eq!(
+        run(Event::Start, 1),
+        vec![Event::Start, Event::Left, Event::Right]
+    );
+}
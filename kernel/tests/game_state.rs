@@ -0,0 +1,139 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkState, ClosureMechanism, EngineState, Mechanism, Mechanisms, Substate,
+    },
+    prelude::*,
+    standard_runtime::{GameState, GameStateMachine, GameStateMechanismExt},
+    util::sync::WriteLock,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    MainMenu,
+    Loading,
+    Playing,
+    Paused,
+}
+
+#[derive(ClockworkState)]
+struct State {
+    mode: GameState<Mode>,
+}
+
+type Log = WriteLock<Vec<String>>;
+
+/// Logs the message
+fn log(log: &Log, message: &'static str) -> impl FnMut(&mut EngineState<State>) + 'static {
+    let log = log.clone();
+    move |_| log.lock_mut().push(message.into())
+}
+
+#[test]
+fn transitions_call_hooks_and_gate_mechanisms() {
+    let events = Log::default();
+    let events_in_loop = events.clone();
+    let gated_log = events.clone();
+    Clockwork::<State, StandardEvent>::builder()
+        .state(State {
+            mode: GameState::new(Mode::MainMenu),
+        })
+        .main_loop(
+            move |mut state, mut mechanisms: Mechanisms<State, StandardEvent>| {
+                mechanisms.clink_event(&mut state, StandardEvent::Initialization);
+                state.request_transition(Mode::Loading);
+                (0..4).for_each(|_| mechanisms.clink_event(&mut state, StandardEvent::Tick));
+                state
+                    .start_access()
+                    .get(|mode: &GameState<Mode>| assert!(mode.is(&Mode::Paused)))
+                    .finish();
+                assert_eq!(
+                    *events_in_loop.lock(),
+                    vec![
+                        "enter main menu",
+                        "exit main menu",
+                        "enter loading",
+                        "update loading",
+                        "exit loading",
+                        "enter playing",
+                        "tick while playing",
+                        "update playing",
+                        "enter paused",
+                    ]
+                )
+            },
+        )
+        .add_game_state_machine(
+            GameStateMachine::default()
+                .on_enter(Mode::MainMenu, log(&events, "enter main menu"))
+                .on_exit(Mode::MainMenu, log(&events, "exit main menu"))
+                .on_enter(Mode::Loading, log(&events, "enter loading"))
+                .on_update(Mode::Loading, log(&events, "update loading"))
+                .on_update(Mode::Loading, |state| {
+                    state.request_transition(Mode::Playing)
+                })
+                .on_exit(Mode::Loading, log(&events, "exit loading"))
+                .on_enter(Mode::Playing, log(&events, "enter playing"))
+                .on_update(Mode::Playing, log(&events, "update playing"))
+                .on_enter(Mode::Paused, log(&events, "enter paused")),
+        )
+        .add_mechanism(
+            ClosureMechanism::new(
+                Some(vec![StandardEvent::Tick]),
+                move |state: &mut EngineState<State>, _: StandardEvent| {
+                    gated_log.lock_mut().push("tick while playing".into());
+                    state.request_transition(Mode::Paused)
+                },
+            )
+            .in_game_states(vec![Mode::Playing]),
+        )
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
+
+/// Logs the mode upon every fixed tick, and is generic over the state
+struct ModeLogger(Log);
+
+impl<S> Mechanism<S, StandardEvent> for ModeLogger
+where
+    S: Substate<GameState<Mode>>,
+{
+    fn clink(&mut self, state: &mut EngineState<S>, _: StandardEvent) {
+        let mode = state
+            .start_access()
+            .get(|mode: &GameState<Mode>| format!("{:?}", mode.current()))
+            .finish();
+        self.0.lock_mut().push(mode)
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        Some(vec![StandardEvent::FixedTick])
+    }
+}
+
+#[test]
+fn transitions_are_applied_before_follow_up_events() {
+    let events = Log::default();
+    let events_in_loop = events.clone();
+    Clockwork::<State, StandardEvent>::builder()
+        .state(State {
+            mode: GameState::new(Mode::Playing),
+        })
+        .main_loop(
+            move |mut state, mut mechanisms: Mechanisms<State, StandardEvent>| {
+                mechanisms.clink_event(&mut state, StandardEvent::FixedTick);
+                mechanisms.clink_event(&mut state, StandardEvent::Tick);
+                assert_eq!(*events_in_loop.lock(), vec!["Playing", "Paused"])
+            },
+        )
+        .add_game_state_machine(GameStateMachine::<State, Mode>::default())
+        .on(StandardEvent::Tick, |state| {
+            state.request_transition(Mode::Paused);
+            state.emit_event(StandardEvent::FixedTick)
+        })
+        .add_mechanism(ModeLogger(events.clone()).in_game_states(vec![Mode::Playing]))
+        .add_mechanism(ModeLogger(events).in_game_states(vec![Mode::Paused]))
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
@@ -0,0 +1,53 @@
+use spc_clockwork_kernel::util::init_state::{InitState, InitStateError};
+
+#[test]
+fn fallible_accessors() {
+    let mut state = InitState::<u8, u16>::from(1);
+    assert_eq!(state.try_get_uninit(), Ok(&1));
+    assert_eq!(
+        state.try_get_init(),
+        Err(InitStateError::Uninit("Tried to read the state"))
+    );
+    assert!(state.try_terminate(|_| {}).is_err());
+    assert!(state.is_uninit());
+
+    state.initialize(|x| x as u16 + 1);
+    assert_eq!(state.try_get_init(), Ok(&2));
+    assert_eq!(
+        state.try_initialize(|x| x as u16),
+        Err(InitStateError::Init("Tried to initialize the state"))
+    );
+    assert_eq!(state.try_get_init(), Ok(&2));
+
+    state.terminate(|_| {});
+    assert!(matches!(
+        state.try_get_init_mut(),
+        Err(InitStateError::Terminated(_))
+    ));
+}
+
+#[test]
+fn restart() {
+    let mut state = InitState::<u8, u16>::from(1);
+    assert_eq!(
+        state.try_restart(|_| 0),
+        Err(InitStateError::Uninit("Tried to restart the state"))
+    );
+
+    state.initialize(|x| x as u16);
+    state.restart(|disposed| disposed.map_or(0, |x| x as u8 + 1));
+    assert_eq!(state.try_get_uninit(), Ok(&2));
+
+    state.initialize(|x| x as u16);
+    state.terminate(|_| {});
+    state.restart(|disposed| disposed.map_or(0, |x| x as u8));
+    assert_eq!(state.try_get_uninit(), Ok(&0));
+}
+
+#[test]
+#[should_panic(
+    expected = "Tried to write to the state, but the state has not yet been initialized"
+)]
+fn bad_access_panics() {
+    InitState::<u8, u16>::from(1).get_init_mut();
+}
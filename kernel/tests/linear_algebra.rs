@@ -0,0 +1,62 @@
+mod common;
+
+use common::assert_approx_eq;
+use spc_clockwork_kernel::math::{Mat3, Mat4, Vec3, Vec4};
+use std::f32::consts::FRAC_PI_2;
+
+#[test]
+fn square_matrices() {
+    let matrix = Mat3::from([[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]]);
+    assert_eq!(matrix.transpose()[(0, 1)], 1.0);
+    assert!((matrix.determinant() - 25.0).abs() < 1e-5);
+    assert_approx_eq(matrix * matrix.inverse().unwrap(), Mat3::identity());
+
+    let singular = Mat3::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(singular.inverse().is_none());
+}
+
+#[test]
+fn vectors() {
+    let x = Vec3::from([1.0, 0.0, 0.0]);
+    let y = Vec3::from([0.0, 2.0, 0.0]);
+    assert_eq!(x.dot(&y), 0.0);
+    assert_eq!(y.norm(), 2.0);
+    assert_eq!(y.normalize(), Vec3::from([0.0, 1.0, 0.0]));
+    assert_eq!(x.cross(&y), Vec3::from([0.0, 0.0, 2.0]));
+}
+
+#[test]
+fn homogeneous_transformations() {
+    let point = Vec4::from([1.0, 0.0, 0.0, 1.0]);
+    let transformation = Mat4::translation([0.0, 0.0, 5.0].into())
+        * Mat4::rotation([0.0, 0.0, 1.0].into(), FRAC_PI_2)
+        * Mat4::scale([2.0, 2.0, 2.0].into());
+    assert_approx_eq(transformation * point, [0.0, 2.0, 5.0, 1.0].into());
+    assert_approx_eq(
+        transformation.inverse().unwrap() * transformation * point,
+        point,
+    );
+
+    let view = Mat4::look_at(
+        [0.0, 0.0, 5.0].into(),
+        [0.0, 0.0, 0.0].into(),
+        [0.0, 1.0, 0.0].into(),
+    );
+    assert_approx_eq(view, Mat4::translation([0.0, 0.0, -5.0].into()));
+}
+
+#[test]
+fn projections() {
+    let perspective = Mat4::perspective(1.0, FRAC_PI_2, 1.0, 10.0);
+    let [_, _, z, w]: [f32; 4] = (perspective * Vec4::from([0.0, 0.0, -1.0, 1.0])).into();
+    assert!((z / w + 1.0).abs() < 1e-5);
+    let [_, _, z, w]: [f32; 4] = (perspective * Vec4::from([0.0, 0.0, -10.0, 1.0])).into();
+    assert!((z / w - 1.0).abs() < 1e-5);
+
+    let orthographic = Mat4::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    assert_approx_eq(
+        orthographic * Vec4::from([2.0, -1.0, -3.0, 1.0]),
+        [1.0, -1.0, 1.0, 1.0].into(),
+    );
+}
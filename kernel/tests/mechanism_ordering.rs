@@ -0,0 +1,125 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkEvent, ClockworkState, EngineState, Mechanism, MechanismOrdering, Mechanisms,
+    },
+    prelude::*,
+    util::sync::WriteLock,
+};
+
+struct State;
+impl ClockworkState for State {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    A,
+    B,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+struct Recorder(
+    &'static str,
+    Option<Vec<Event>>,
+    WriteLock<Vec<(&'static str, Event)>>,
+);
+
+impl Mechanism<State, Event> for Recorder {
+    fn clink(&mut self, _: &mut EngineState<State>, event: Event) {
+        self.2.lock_mut().push((self.0, event))
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        self.1.clone()
+    }
+}
+
+fn run(
+    mechanisms: Vec<(&'static str, Option<Vec<Event>>, MechanismOrdering)>,
+) -> Result<Vec<(&'static str, Event)>, String> {
+    let log = WriteLock::from(Vec::new());
+    mechanisms
+        .into_iter()
+        .fold(
+            Clockwork::<State, Event>::builder().state(State).main_loop(
+                |mut state, mut mechanisms: Mechanisms<_, _>| {
+                    mechanisms.clink_event(&mut state, Event::A);
+                    mechanisms.clink_event(&mut state, Event::B);
+                },
+            ),
+            |builder, (name, events, ordering)| {
+                builder.add_ordered_mechanism(Recorder(name, events, log.clone()), ordering)
+            },
+        )
+        .build()
+        .map_err(|error| error.to_string())?
+        .set_the_clock();
+    let result = log.lock().clone();
+    Ok(result)
+}
+
+#[test]
+fn insertion_order_without_constraints() {
+    assert_eq!(
+        run(vec![
+            ("any", None, Default::default()),
+            ("a", Some(vec![Event::A]), Default::default()),
+            ("b", Some(vec![Event::B]), Default::default()),
+        ]),
+        Ok(vec![
+            ("a", Event::A),
+            ("any", Event::A),
+            ("b", Event::B),
+            ("any", Event::B),
+        ])
+    )
+}
+
+#[test]
+fn before_and_after_constraints() {
+    assert_eq!(
+        run(vec![
+            (
+                "render",
+                Some(vec![Event::A]),
+                MechanismOrdering::labeled("rendering").after("physics"),
+            ),
+            (
+                "physics",
+                Some(vec![Event::A]),
+                MechanismOrdering::labeled("physics"),
+            ),
+            (
+                "input",
+                Some(vec![Event::A]),
+                MechanismOrdering::default().before("physics"),
+            ),
+            (
+                "any",
+                None,
+                MechanismOrdering::default().before("rendering"),
+            ),
+        ]),
+        Ok(vec![
+            ("input", Event::A),
+            ("physics", Event::A),
+            ("any", Event::A),
+            ("render", Event::A),
+            ("any", Event::B),
+        ])
+    )
+}
+
+#[test]
+fn cycles_are_build_errors() {
+    assert!(run(vec![
+        ("a", None, MechanismOrdering::labeled("a").before("b")),
+        ("b", None, MechanismOrdering::labeled("b").before("a")),
+    ])
+    .is_err())
+}
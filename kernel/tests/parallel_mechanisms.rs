@@ -0,0 +1,171 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkEvent, ClockworkState, ErrorPolicy, MechanismOrdering, Mechanisms,
+        ParallelMechanism, SharedEngineState, SubstateAccess,
+    },
+    prelude::*,
+};
+
+#[derive(Default)]
+struct Physics(Vec<&'static str>);
+impl ClockworkState for Physics {}
+
+#[derive(Default)]
+struct Audio(Vec<&'static str>);
+impl ClockworkState for Audio {}
+
+#[derive(ClockworkState, Default)]
+struct State {
+    physics: Physics,
+    audio: Audio,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    Tick,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+struct PhysicsWriter(&'static str);
+
+impl ParallelMechanism<State, Event> for PhysicsWriter {
+    fn clink(&mut self, state: &mut SharedEngineState<'_, State>, _: Event) {
+        state.get_mut(|Physics(log)| log.push(self.0))
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        Some(vec![Event::Tick])
+    }
+
+    fn substate_access(&self) -> SubstateAccess<State> {
+        SubstateAccess::default().write::<Physics>()
+    }
+}
+
+struct AudioWriter;
+
+impl ParallelMechanism<State, Event> for AudioWriter {
+    fn clink(&mut self, state: &mut SharedEngineState<'_, State>, _: Event) {
+        let bodies = state.get(|Physics(log): &Physics| log.len());
+        state.get_mut(|Audio(log)| {
+            log.push(match bodies {
+                0 => "quiet",
+                _ => "loud",
+            })
+        })
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        Some(vec![Event::Tick])
+    }
+
+    fn substate_access(&self) -> SubstateAccess<State> {
+        SubstateAccess::default().read::<Physics>().write::<Audio>()
+    }
+}
+
+struct Ambience;
+
+impl ParallelMechanism<State, Event> for Ambience {
+    fn clink(&mut self, state: &mut SharedEngineState<'_, State>, _: Event) {
+        state.get_mut(|Audio(log)| log.push("ambience"))
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        Some(vec![Event::Tick])
+    }
+
+    fn substate_access(&self) -> SubstateAccess<State> {
+        SubstateAccess::default().write::<Audio>()
+    }
+}
+
+struct Undeclared;
+
+impl ParallelMechanism<State, Event> for Undeclared {
+    fn clink(&mut self, state: &mut SharedEngineState<'_, State>, _: Event) {
+        state.get_mut(|Audio(log)| log.push("undeclared"))
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+
+    fn substate_access(&self) -> SubstateAccess<State> {
+        SubstateAccess::default().read::<Audio>()
+    }
+}
+
+#[test]
+fn conflicting_mechanisms_keep_their_order() {
+    Clockwork::<State, Event>::builder()
+        .state(State::default())
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            mechanisms.clink_event(&mut state, Event::Tick);
+            state
+                .start_access()
+                .get(|Physics(log): &Physics| assert_eq!(log, &["first", "second"]))
+                .finish();
+            state
+                .start_access()
+                .get(|Audio(log): &Audio| assert_eq!(log, &["loud"]))
+                .finish();
+        })
+        .add_ordered_parallel_mechanism(AudioWriter, MechanismOrdering::default().after("first"))
+        .add_ordered_parallel_mechanism(
+            PhysicsWriter("second"),
+            MechanismOrdering::default().after("first"),
+        )
+        .add_ordered_parallel_mechanism(PhysicsWriter("first"), MechanismOrdering::labeled("first"))
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
+
+#[test]
+fn non_conflicting_mechanisms_are_all_clinked() {
+    Clockwork::<State, Event>::builder()
+        .state(State::default())
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            mechanisms.clink_event(&mut state, Event::Tick);
+            state
+                .start_access()
+                .get(|Physics(log): &Physics| assert_eq!(log, &["physics"]))
+                .finish();
+            state
+                .start_access()
+                .get(|Audio(log): &Audio| assert_eq!(log, &["ambience"]))
+                .finish();
+        })
+        .add_parallel_mechanism(Ambience)
+        .add_parallel_mechanism(PhysicsWriter("physics"))
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
+
+#[test]
+fn undeclared_access_is_a_failure() {
+    Clockwork::<State, Event>::builder()
+        .state(State::default())
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            mechanisms.clink_event(&mut state, Event::Tick);
+            assert!(mechanisms.is_termination_requested());
+            state
+                .start_access()
+                .get(|Audio(log): &Audio| assert!(log.is_empty()))
+                .finish();
+        })
+        .add_parallel_mechanism(Undeclared)
+        .default_error_policy(ErrorPolicy::Terminate)
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
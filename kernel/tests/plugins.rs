@@ -0,0 +1,138 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkState, Substate},
+    clockwork::{ClockworkBuilder, ClockworkBuilderError},
+    prelude::*,
+};
+
+#[derive(Default)]
+struct Score(u32);
+impl ClockworkState for Score {}
+
+#[derive(Default)]
+struct Lives(u32);
+impl ClockworkState for Lives {}
+
+#[derive(Default, spc_clockwork_kernel::abstract_runtime::ClockworkState)]
+struct State {
+    score: Score,
+    lives: Lives,
+}
+
+struct ScorePlugin(u32);
+
+impl<S> Plugin<S, StandardEvent> for ScorePlugin
+where
+    S: Substate<Score>,
+{
+    fn name(&self) -> String {
+        "score".into()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self.0 {
+            0 => Err("points per tick must be positive".into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn install(
+        self,
+        builder: ClockworkBuilder<S, StandardEvent>,
+    ) -> Result<ClockworkBuilder<S, StandardEvent>, ClockworkBuilderError> {
+        let points = self.0;
+        Ok(builder.on(StandardEvent::Tick, move |state| {
+            state
+                .start_mutate()
+                .get_mut(|score: &mut Score| score.0 += points)
+                .finish()
+        }))
+    }
+}
+
+/// Installs the score plugin as well
+struct GamePlugin;
+
+impl<S> Plugin<S, StandardEvent> for GamePlugin
+where
+    S: Substate<Score> + Substate<Lives>,
+{
+    fn install(
+        self,
+        builder: ClockworkBuilder<S, StandardEvent>,
+    ) -> Result<ClockworkBuilder<S, StandardEvent>, ClockworkBuilderError> {
+        Ok(builder
+            .add_plugin(ScorePlugin(10))
+            .on(StandardEvent::Initialization, |state| {
+                state
+                    .start_mutate()
+                    .get_mut(|lives: &mut Lives| lives.0 = 3)
+                    .finish()
+            }))
+    }
+}
+
+/// Fails to install
+struct BrokenPlugin;
+
+impl<S> Plugin<S, StandardEvent> for BrokenPlugin
+where
+    S: ClockworkState,
+{
+    fn install(
+        self,
+        _: ClockworkBuilder<S, StandardEvent>,
+    ) -> Result<ClockworkBuilder<S, StandardEvent>, ClockworkBuilderError> {
+        Err(ClockworkBuilderError::ValidationError(
+            "no graphics device".into(),
+        ))
+    }
+}
+
+fn builder() -> ClockworkBuilder<State, StandardEvent> {
+    Clockwork::builder()
+        .state(State::default())
+        .main_loop(|mut state, mut mechanisms| {
+            mechanisms.clink_event(&mut state, StandardEvent::Initialization);
+            mechanisms.clink_event(&mut state, StandardEvent::Tick);
+            mechanisms.clink_event(&mut state, StandardEvent::Tick);
+            state
+                .start_access()
+                .get(|Score(score)| assert_eq!(*score, 22))
+                .get(|Lives(lives)| assert_eq!(*lives, 3))
+                .finish()
+        })
+}
+
+#[test]
+fn plugins_install_their_mechanisms_and_plugins() {
+    builder()
+        .add_plugin(GamePlugin)
+        .add_plugin(ScorePlugin(1))
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
+
+#[test]
+fn invalid_plugins_fail_the_build() {
+    let error = builder()
+        .add_plugin(ScorePlugin(0))
+        .build()
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid configuration of plugin score: points per tick must be positive"
+    );
+}
+
+#[test]
+fn failed_installations_fail_the_build() {
+    let error = builder()
+        .add_plugin(GamePlugin)
+        .add_plugin(BrokenPlugin)
+        .build()
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(error.to_string(), "no graphics device");
+}
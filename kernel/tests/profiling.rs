@@ -0,0 +1,119 @@
+#![cfg(feature = "profiling")]
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkEvent, ClockworkState, EngineState, Mechanism, MechanismOrdering,
+        MechanismProfiles, Mechanisms, Substate,
+    },
+    prelude::*,
+};
+use std::time::Duration;
+
+#[derive(Default)]
+struct State(MechanismProfiles);
+impl ClockworkState for State {}
+
+impl Substate<MechanismProfiles> for State {
+    fn substate<R>(&self, callback: impl FnOnce(&MechanismProfiles) -> R) -> R {
+        callback(&self.0)
+    }
+
+    fn substate_mut<R>(&mut self, callback: impl FnOnce(&mut MechanismProfiles) -> R) -> R {
+        callback(&mut self.0)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    Tick,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+struct Sleeper(Duration);
+
+impl Mechanism<State, Event> for Sleeper {
+    fn clink(&mut self, _: &mut EngineState<State>, _: Event) {
+        std::thread::sleep(self.0)
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+}
+
+#[test]
+fn mechanisms_are_timed_and_traced() {
+    Clockwork::<State, Event>::builder()
+        .state(State::default())
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            state
+                .start_mutate()
+                .get_mut(MechanismProfiles::start_trace)
+                .finish();
+            (0..3).for_each(|_| mechanisms.clink_event(&mut state, Event::Tick));
+            state
+                .start_access()
+                .get(|profiles: &MechanismProfiles| {
+                    let (id, slow) = profiles.get_by_name("slow").exactly_one().ok().unwrap();
+                    assert_eq!(slow.count(), 3);
+                    assert!(slow.average() >= Duration::from_millis(2));
+                    assert!(slow.max() >= slow.average());
+                    assert_eq!(profiles.timings().len(), 2);
+                    assert_eq!(profiles.get(id).unwrap().name(), "slow");
+
+                    let trace = profiles.chrome_trace();
+                    assert!(trace.starts_with("{\"traceEvents\":[{\"name\":\"slow\""));
+                    assert_eq!(trace.matches("\"ph\":\"X\"").count(), 6);
+                    assert_eq!(
+                        trace
+                            .matches(&format!(
+                                "\"args\":{{\"event\":\"Tick\",\"mechanism\":{}}}",
+                                id
+                            ))
+                            .count(),
+                        3
+                    );
+                })
+                .finish()
+        })
+        .add_ordered_mechanism(
+            Sleeper(Duration::from_millis(2)),
+            MechanismOrdering::labeled("slow"),
+        )
+        .add_mechanism(Sleeper(Duration::default()))
+        .profile_mechanisms()
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
+
+#[test]
+fn mechanisms_sharing_the_name_are_profiled_separately() {
+    Clockwork::<State, Event>::builder()
+        .state(State::default())
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            (0..2).for_each(|_| mechanisms.clink_event(&mut state, Event::Tick));
+            state
+                .start_access()
+                .get(|profiles: &MechanismProfiles| {
+                    let sleepers = profiles
+                        .get_by_name(std::any::type_name::<Sleeper>())
+                        .collect_vec();
+                    assert_eq!(sleepers.len(), 2);
+                    assert!(sleepers.iter().all(|(_, timings)| timings.count() == 2))
+                })
+                .finish()
+        })
+        .add_mechanism(Sleeper(Duration::default()))
+        .add_mechanism(Sleeper(Duration::default()))
+        .profile_mechanisms()
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
@@ -0,0 +1,102 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkEvent, ClockworkState, EngineState, Mechanism, MechanismOrdering, Mechanisms,
+    },
+    prelude::*,
+    util::sync::WriteLock,
+};
+
+struct State;
+impl ClockworkState for State {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    AddDebug,
+    Disable,
+    Enable,
+    Remove,
+    Nothing,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+type Log = WriteLock<Vec<String>>;
+
+struct DebugTool(Log);
+
+impl Mechanism<State, Event> for DebugTool {
+    fn clink(&mut self, _: &mut EngineState<State>, event: Event) {
+        self.0.lock_mut().push(format!("debug {:?}", event))
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+
+    fn removal(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push("debug removed".into())
+    }
+}
+
+struct Controller(Log);
+
+impl Mechanism<State, Event> for Controller {
+    fn clink(&mut self, state: &mut EngineState<State>, event: Event) {
+        match event {
+            Event::AddDebug => state.add_mechanism(
+                DebugTool(self.0.clone()),
+                MechanismOrdering::labeled("debug"),
+            ),
+            Event::Disable => state.disable_mechanisms("debug"),
+            Event::Enable => state.enable_mechanisms("debug"),
+            Event::Remove => state.remove_mechanisms("debug"),
+            Event::Nothing => {}
+        }
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+}
+
+#[test]
+fn mechanisms_are_changed_between_events() {
+    let log = Log::default();
+    Clockwork::<State, Event>::builder()
+        .state(State)
+        .main_loop(|mut state, mut mechanisms: Mechanisms<_, _>| {
+            [
+                Event::AddDebug,
+                Event::Nothing,
+                Event::Disable,
+                Event::Nothing,
+                Event::Enable,
+                Event::Nothing,
+                Event::Remove,
+                Event::Nothing,
+            ]
+            .iter()
+            .for_each(|&event| mechanisms.clink_event(&mut state, event))
+        })
+        .add_mechanism(Controller(log.clone()))
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let log = log.lock().clone();
+    assert_eq!(
+        log,
+        vec![
+            "debug Nothing",
+            "debug Disable",
+            "debug Nothing",
+            "debug Remove",
+            "debug removed",
+        ]
+    )
+}
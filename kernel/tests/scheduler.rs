@@ -0,0 +1,183 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkEvent, ClockworkState, EngineState, Mechanism, Mechanisms},
+    prelude::*,
+    standard_runtime::{Scheduler, SchedulerMechanism, StandardRuntimeStatistics},
+    util::sync::WriteLock,
+};
+use std::{
+    convert::TryFrom,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    Standard(StandardEvent),
+    Ping(u32),
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+impl From<StandardEvent> for Event {
+    fn from(event: StandardEvent) -> Self {
+        Event::Standard(event)
+    }
+}
+
+impl TryFrom<Event> for StandardEvent {
+    type Error = ();
+
+    fn try_from(event: Event) -> Result<Self, ()> {
+        match event {
+            Event::Standard(event) => Ok(event),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Ticks every 100 ms
+struct Statistics(Instant);
+impl ClockworkState for Statistics {}
+
+impl StandardRuntimeStatistics for Statistics {
+    type Frequency = f32;
+    type Count = u64;
+
+    fn duration_to_freq(duration: Duration) -> f32 {
+        1f32 / duration.as_secs_f32()
+    }
+
+    fn current_tick_delta(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn desired_avg_tick_delta(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn current_draw_delta(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn desired_min_draw_period(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    fn init_time(&self) -> Instant {
+        self.0
+    }
+
+    fn ticks_total(&self) -> u64 {
+        0
+    }
+
+    fn frames_total(&self) -> u64 {
+        0
+    }
+}
+
+#[derive(spc_clockwork_kernel::abstract_runtime::ClockworkState)]
+struct State {
+    statistics: Statistics,
+    scheduler: Scheduler<Event>,
+}
+
+struct Listener(WriteLock<Vec<Event>>);
+
+impl Mechanism<State, Event> for Listener {
+    fn clink(&mut self, _: &mut EngineState<State>, event: Event) {
+        self.0.lock_mut().push(event)
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+}
+
+const TICK: Event = Event::Standard(StandardEvent::Tick);
+
+fn run(
+    main_loop: impl FnOnce(&mut EngineState<State>, &mut Mechanisms<State, Event>) + 'static,
+) -> Vec<Event> {
+    let events = WriteLock::from(Vec::new());
+    Clockwork::<State, Event>::builder()
+        .state(State {
+            statistics: Statistics(Instant::now()),
+            scheduler: Default::default(),
+        })
+        .main_loop(move |mut state, mut mechanisms| main_loop(&mut state, &mut mechanisms))
+        .add_standard_mechanism(SchedulerMechanism::<Event, Statistics>::default())
+        .add_mechanism(Listener(events.clone()))
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let events = events.lock().clone();
+    events
+}
+
+fn schedule<R>(
+    state: &mut EngineState<State>,
+    callback: impl FnOnce(&mut Scheduler<Event>) -> R,
+) -> R {
+    state.start_mutate().get_mut(callback).finish()
+}
+
+#[test]
+fn timers_are_due_in_tick_time() {
+    let events = run(|state, mechanisms| {
+        schedule(state, |scheduler| {
+            scheduler.schedule_repeating(Duration::from_millis(150), Event::Ping(1));
+            scheduler.schedule_once(Duration::from_millis(250), Event::Ping(2));
+        });
+        (0..4).for_each(|_| mechanisms.clink_event(state, TICK));
+    });
+    assert_eq!(
+        events,
+        vec![
+            TICK,
+            TICK,
+            Event::Ping(1),
+            TICK,
+            Event::Ping(2),
+            Event::Ping(1),
+            TICK,
+        ]
+    );
+}
+
+#[test]
+fn timers_are_paused_scaled_and_cancelled() {
+    let events = run(|state, mechanisms| {
+        let (paused, cancelled) = schedule(state, |scheduler| {
+            scheduler.set_time_scale(2f32);
+            (
+                scheduler.schedule_once(Duration::from_millis(200), Event::Ping(1)),
+                scheduler.schedule_once(Duration::from_millis(200), Event::Ping(2)),
+            )
+        });
+        schedule(state, |scheduler| {
+            scheduler.pause(paused);
+            assert!(scheduler.cancel(cancelled));
+            assert!(!scheduler.cancel(cancelled));
+        });
+        mechanisms.clink_event(state, TICK);
+        schedule(state, |scheduler| {
+            assert_eq!(
+                scheduler.remaining(paused),
+                Some(Duration::from_millis(200))
+            );
+            scheduler.resume(paused);
+            scheduler.pause_all();
+        });
+        mechanisms.clink_event(state, TICK);
+        schedule(state, Scheduler::resume_all);
+        mechanisms.clink_event(state, TICK);
+        schedule(state, |scheduler| assert!(!scheduler.is_scheduled(paused)));
+    });
+    assert_eq!(events, vec![TICK, TICK, TICK, Event::Ping(1)]);
+}
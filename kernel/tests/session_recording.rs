@@ -0,0 +1,155 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkEvent, ClockworkState, EngineState, Mechanism, Mechanisms, RecordingError,
+        SessionEntry, SessionRecording, SessionTiming,
+    },
+    prelude::*,
+};
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+enum Event {
+    Tick,
+    Bonus(u32),
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+#[derive(Default)]
+struct Score {
+    points: u32,
+}
+impl ClockworkState for Score {}
+
+#[derive(Default, spc_clockwork_kernel::abstract_runtime::ClockworkState)]
+struct State {
+    score: Score,
+    recording: SessionRecording<Event, char>,
+}
+
+struct Scorer;
+
+impl Mechanism<State, Event> for Scorer {
+    fn clink(&mut self, state: &mut EngineState<State>, event: Event) {
+        state
+            .start_mutate()
+            .get_mut(|score: &mut Score| {
+                score.points += match event {
+                    Event::Tick => 1,
+                    Event::Bonus(points) => points,
+                }
+            })
+            .finish()
+    }
+
+    fn handled_events(&self) -> Option<Vec<Event>> {
+        None
+    }
+}
+
+fn clockwork(main_loop: impl FnOnce(EngineState<State>, Mechanisms<State, Event>) + 'static) {
+    Clockwork::<State, Event>::builder()
+        .state(State::default())
+        .main_loop(main_loop)
+        .add_mechanism(Scorer)
+        .record_session::<char>()
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
+
+#[test]
+fn only_started_sessions_are_recorded() {
+    clockwork(|mut state, mut mechanisms| {
+        mechanisms.clink_event(&mut state, Event::Tick);
+        state
+            .start_mutate()
+            .get_mut(SessionRecording::<Event, char>::start)
+            .finish();
+        mechanisms.clink_event(&mut state, Event::Bonus(5));
+        state
+            .start_mutate()
+            .get_mut(|recording: &mut SessionRecording<Event, char>| {
+                recording.record_input('w');
+                recording.stop();
+                recording.record_input('s')
+            })
+            .finish();
+        mechanisms.clink_event(&mut state, Event::Tick);
+        state
+            .start_access()
+            .get(|recording: &SessionRecording<Event, char>| {
+                assert_eq!(
+                    recording.entries(),
+                    &[
+                        SessionEntry::Event(Event::Bonus(5)),
+                        SessionEntry::Input('w')
+                    ]
+                )
+            })
+            .finish()
+    })
+}
+
+#[test]
+fn saved_session_is_loaded_identically() {
+    let timing = SessionTiming {
+        tick_delta: Duration::from_millis(16),
+        draw_delta: Duration::from_millis(33),
+        ticks_total: 1,
+        frames_total: 0,
+    };
+    clockwork(move |mut state, mut mechanisms| {
+        state
+            .start_mutate()
+            .get_mut(SessionRecording::<Event, char>::start)
+            .finish();
+        mechanisms.clink_event(&mut state, Event::Tick);
+        state
+            .start_mutate()
+            .get_mut(|recording: &mut SessionRecording<Event, char>| {
+                recording.record_timing(timing)
+            })
+            .finish();
+        mechanisms.clink_event(&mut state, Event::Bonus(10));
+        mechanisms.clink_event(&mut state, Event::Tick);
+
+        let mut file = Vec::new();
+        let recorded = state
+            .start_access()
+            .get(|recording: &SessionRecording<Event, char>| {
+                recording.save(&mut file).unwrap();
+                recording.entries().to_vec()
+            })
+            .finish();
+        assert_eq!(
+            recorded,
+            vec![
+                SessionEntry::Event(Event::Tick),
+                SessionEntry::Timing(timing),
+                SessionEntry::Event(Event::Bonus(10)),
+                SessionEntry::Event(Event::Tick),
+            ]
+        );
+        assert_eq!(
+            SessionRecording::<Event, char>::load(file.as_slice()).unwrap(),
+            recorded
+        );
+        state
+            .start_access()
+            .get(|score: &Score| assert_eq!(score.points, 12))
+            .finish()
+    })
+}
+
+#[test]
+fn foreign_files_are_rejected() {
+    let error = SessionRecording::<Event, char>::load(&b"not a recording"[..]).unwrap_err();
+    assert!(matches!(error, RecordingError::NotARecording));
+}
@@ -0,0 +1,135 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkState, Snapshot, SnapshotError, SnapshotFormat, Snapshots},
+    prelude::*,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Physics {
+    positions: Vec<(f32, f32)>,
+    gravity: f32,
+}
+impl ClockworkState for Physics {}
+impl Snapshot for Physics {
+    const KEY: &'static str = "physics";
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Score {
+    points: u32,
+    #[serde(skip)]
+    cached_label: Option<String>,
+}
+impl ClockworkState for Score {}
+impl Snapshot for Score {
+    const KEY: &'static str = "score";
+    const VERSION: u32 = 2;
+
+    fn restore(&mut self, restored: Self) {
+        self.points = restored.points
+    }
+}
+
+#[derive(Default, spc_clockwork_kernel::abstract_runtime::ClockworkState)]
+struct State {
+    physics: Physics,
+    score: Score,
+    #[clockwork(skip)]
+    frames: u64,
+}
+
+fn state() -> State {
+    State {
+        physics: Physics {
+            positions: vec![(1.0, 2.0), (3.5, -4.0)],
+            gravity: -9.8,
+        },
+        score: Score {
+            points: 42,
+            cached_label: Some("42 points".into()),
+        },
+        frames: 7,
+    }
+}
+
+fn roundtrip(format: SnapshotFormat) {
+    let snapshots = Snapshots::<State>::default()
+        .with::<Physics>()
+        .with::<Score>();
+    let archive = snapshots.save(&state(), format).unwrap();
+
+    let mut restored = State::default();
+    restored.score.cached_label = Some("stale".into());
+    snapshots.restore(&mut restored, &archive, format).unwrap();
+
+    assert_eq!(restored.physics, state().physics);
+    assert_eq!(restored.score.points, 42);
+    assert_eq!(restored.score.cached_label.as_deref(), Some("stale"));
+    assert_eq!(restored.frames, 0);
+}
+
+#[test]
+fn binary_snapshot_roundtrip() {
+    roundtrip(SnapshotFormat::Binary)
+}
+
+#[test]
+fn ron_snapshot_roundtrip() {
+    roundtrip(SnapshotFormat::Ron);
+    let archive = Snapshots::<State>::default()
+        .with::<Score>()
+        .save(&state(), SnapshotFormat::Ron)
+        .unwrap();
+    assert!(String::from_utf8(archive).unwrap().contains("score"));
+}
+
+#[test]
+fn missing_substate_leaves_state_untouched() {
+    let archive = Snapshots::<State>::default()
+        .with::<Physics>()
+        .save(&state(), SnapshotFormat::Binary)
+        .unwrap();
+    let mut restored = State::default();
+    let error = Snapshots::<State>::default()
+        .with::<Physics>()
+        .with::<Score>()
+        .restore(&mut restored, &archive, SnapshotFormat::Binary)
+        .unwrap_err();
+
+    assert!(matches!(error, SnapshotError::MissingSubstate(key) if key == "score"));
+    assert_eq!(restored.physics, Physics::default());
+}
+
+#[test]
+fn substate_version_mismatch_is_rejected() {
+    #[derive(Default, Serialize, Deserialize)]
+    struct OldScore {
+        points: u32,
+    }
+    impl ClockworkState for OldScore {}
+    impl Snapshot for OldScore {
+        const KEY: &'static str = "score";
+    }
+
+    #[derive(Default, spc_clockwork_kernel::abstract_runtime::ClockworkState)]
+    struct OldState {
+        score: OldScore,
+    }
+
+    let archive = Snapshots::<OldState>::default()
+        .with::<OldScore>()
+        .save(&OldState::default(), SnapshotFormat::Ron)
+        .unwrap();
+    let error = Snapshots::<State>::default()
+        .with::<Score>()
+        .restore(&mut State::default(), &archive, SnapshotFormat::Ron)
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        SnapshotError::SubstateVersion {
+            expected: 2,
+            found: 1,
+            ..
+        }
+    ));
+}
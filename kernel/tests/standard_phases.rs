@@ -0,0 +1,119 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{ClockworkEvent, ClockworkState, EngineState},
+    prelude::*,
+    standard_runtime::{standard_mechanism, StandardMechanism},
+    util::sync::WriteLock,
+};
+use std::convert::{TryFrom, TryInto};
+
+struct State;
+impl ClockworkState for State {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Event {
+    Standard(StandardEvent),
+    Custom,
+}
+
+impl ClockworkEvent for Event {
+    type Kind = Self;
+
+    fn kind(&self) -> Self {
+        *self
+    }
+}
+
+impl From<StandardEvent> for Event {
+    fn from(event: StandardEvent) -> Self {
+        Event::Standard(event)
+    }
+}
+
+impl TryFrom<Event> for StandardEvent {
+    type Error = ();
+
+    fn try_from(event: Event) -> Result<Self, ()> {
+        match event {
+            Event::Standard(event) => Ok(event),
+            Event::Custom => Err(()),
+        }
+    }
+}
+
+type Log = WriteLock<Vec<&'static str>>;
+
+/// Handles every phase around the tick, and the draw call
+struct Phases(Log);
+
+#[standard_mechanism]
+impl StandardMechanism<State> for Phases {
+    fn fixed_tick(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push("fixed tick")
+    }
+
+    fn late_tick(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push("late tick")
+    }
+
+    fn pre_draw(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push("pre draw")
+    }
+
+    fn post_draw(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push("post draw")
+    }
+}
+
+/// Only handles the late tick
+struct LateTicker(Log);
+
+impl StandardMechanism<State> for LateTicker {
+    fn late_tick(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push("late ticker")
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        Some(vec![StandardEvent::LateTick])
+    }
+}
+
+#[test]
+fn standard_phases_are_converted_from_supersets() {
+    StandardEvent::TICK_PHASES
+        .iter()
+        .chain(&StandardEvent::DRAW_PHASES)
+        .chain(&[StandardEvent::FixedTick])
+        .for_each(|&event| assert_eq!(Event::from(event).try_into(), Ok(event)))
+}
+
+#[test]
+fn standard_mechanisms_handle_standard_phases() {
+    let log = Log::default();
+    let log_in_loop = log.clone();
+    Clockwork::<State, Event>::builder()
+        .state(State)
+        .main_loop(move |mut state, mut mechanisms| {
+            Some(StandardEvent::FixedTick)
+                .into_iter()
+                .chain(StandardEvent::TICK_PHASES.iter().copied())
+                .chain(StandardEvent::DRAW_PHASES.iter().copied())
+                .map(Event::from)
+                .chain(Some(Event::Custom))
+                .for_each(|event| mechanisms.clink_event(&mut state, event));
+            assert_eq!(
+                *log_in_loop.lock(),
+                vec![
+                    "fixed tick",
+                    "late tick",
+                    "late ticker",
+                    "pre draw",
+                    "post draw"
+                ]
+            )
+        })
+        .add_standard_mechanism(Phases(log.clone()))
+        .add_standard_mechanism(LateTicker(log))
+        .build()
+        .unwrap()
+        .set_the_clock();
+}
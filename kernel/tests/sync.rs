@@ -1,5 +1,13 @@
-use spc_clockwork_kernel::util::sync::WriteLock;
-use std::thread;
+use spc_clockwork_kernel::util::sync::{LockError, WriteLock};
+use std::{
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Barrier, Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 #[test]
 fn parallel_counter_increment() {
@@ -13,3 +21,125 @@ fn parallel_counter_increment() {
         .for_each(|t| t.join().unwrap());
     assert_eq!(*counter.downgrade_to_read_lock().lock(), 2500)
 }
+
+#[test]
+fn try_lock_would_block() {
+    let lock = WriteLock::from(0u16);
+    let guard = lock.lock_mut();
+    assert!(matches!(
+        lock.try_lock(),
+        Err(LockError::WouldBlock { id, .. }) if id == lock.id()
+    ));
+    assert!(matches!(
+        lock.lock_mut_timeout(Duration::from_millis(10)),
+        Err(LockError::Timeout { .. })
+    ));
+    drop(guard);
+    assert_eq!(*lock.try_lock().unwrap(), 0);
+    *lock.lock_mut_timeout(Duration::from_millis(10)).unwrap() += 1;
+    assert_eq!(*lock.try_lock_mut().unwrap(), 1);
+}
+
+#[test]
+fn poisoning_recovery() {
+    let lock = WriteLock::from(0u16);
+    let panicking = lock.clone();
+    thread::spawn(move || {
+        let mut guard = panicking.lock_mut();
+        *guard += 1;
+        panic!("Poisoning the lock")
+    })
+    .join()
+    .unwrap_err();
+    assert!(lock.is_poisoned());
+    assert_eq!(
+        lock.try_lock().err(),
+        Some(LockError::Poisoned { id: lock.id() })
+    );
+    assert!(panic::catch_unwind(|| *lock.lock()).is_err());
+
+    *lock.recover() = 0;
+    assert!(!lock.is_poisoned());
+    assert_eq!(*lock.lock(), 0);
+}
+
+#[test]
+fn generations_and_subscriptions() {
+    let lock = WriteLock::from(0u16);
+    let read_lock = lock.downgrade_to_read_lock();
+    let seen = read_lock.generation();
+    assert!(!read_lock.changed_since(seen));
+
+    let notified = Arc::new(Mutex::new(Vec::new()));
+    let subscription = read_lock.subscribe({
+        let notified = notified.clone();
+        let read_lock = read_lock.clone();
+        move |generation| {
+            notified
+                .lock()
+                .unwrap()
+                .push((generation, *read_lock.lock()))
+        }
+    });
+    *lock.lock_mut() += 1;
+    assert!(read_lock.changed_since(seen));
+    assert_eq!(read_lock.generation(), seen + 1);
+
+    assert!(lock.unsubscribe(subscription));
+    assert!(!lock.unsubscribe(subscription));
+    *lock.lock_mut() += 1;
+    assert_eq!(read_lock.generation(), seen + 2);
+    assert_eq!(*notified.lock().unwrap(), vec![(seen + 1, 1)]);
+}
+
+#[test]
+fn subscribers_may_write_the_value() {
+    let lock = WriteLock::from(0u16);
+    lock.subscribe({
+        let lock = lock.clone();
+        move |_| {
+            let mut value = lock.lock_mut();
+            if *value > 10 {
+                *value = 10
+            }
+        }
+    });
+    let notified = Arc::new(Mutex::new(Vec::new()));
+    lock.subscribe({
+        let notified = notified.clone();
+        let lock = lock.clone();
+        move |generation| notified.lock().unwrap().push((generation, *lock.lock()))
+    });
+    *lock.lock_mut() = 20;
+    assert_eq!(*lock.lock(), 10);
+    assert_eq!(*notified.lock().unwrap(), vec![(2, 10), (1, 10)]);
+}
+
+#[test]
+fn subscribers_may_write_the_value_from_different_threads() {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let lock = WriteLock::from(0u16);
+        let barrier = Arc::new(Barrier::new(2));
+        (0..2).for_each(|_| {
+            let writer = lock.clone();
+            let barrier = barrier.clone();
+            let called = AtomicBool::new(false);
+            // Both callbacks write, while the other one is being called by another thread
+            lock.subscribe(move |_| {
+                if !called.swap(true, Ordering::Relaxed) {
+                    barrier.wait();
+                    *writer.lock_mut() += 1
+                }
+            });
+        });
+        (0..2)
+            .map(|_| lock.clone())
+            .map(|lock| thread::spawn(move || *lock.lock_mut() += 1))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|t| t.join().unwrap());
+        sender.send(*lock.lock()).unwrap();
+    });
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(4));
+}
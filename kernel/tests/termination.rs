@@ -0,0 +1,116 @@
+use spc_clockwork_kernel::{
+    abstract_runtime::{
+        ClockworkExit, ClockworkState, EngineState, ErrorPolicy, Mechanisms, TerminationReason,
+    },
+    clockwork::ClockworkBuilder,
+    prelude::*,
+    standard_runtime::{standard_mechanism, StandardMechanism},
+    util::sync::WriteLock,
+};
+
+struct State;
+impl ClockworkState for State {}
+
+type Log = WriteLock<Vec<StandardEvent>>;
+
+struct Logger(Log);
+
+#[standard_mechanism]
+impl StandardMechanism<State> for Logger {
+    fn tick(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push(StandardEvent::Tick)
+    }
+
+    fn termination(&mut self, _: &mut EngineState<State>) {
+        self.0.lock_mut().push(StandardEvent::Termination)
+    }
+}
+
+struct Quitter;
+
+#[standard_mechanism]
+impl StandardMechanism<State> for Quitter {
+    fn tick(&mut self, state: &mut EngineState<State>) {
+        state.request_termination_with_code(3, "Game over");
+        state.request_termination("Ignored, as the termination has already been requested");
+    }
+}
+
+struct Failing;
+
+#[standard_mechanism]
+impl StandardMechanism<State> for Failing {
+    fn tick(&mut self, _: &mut EngineState<State>) {
+        panic!("Broken mechanism")
+    }
+}
+
+/// Runs the main loop, which ticks until the termination is requested,
+/// and tries to terminate twice
+fn main_loop(mut state: EngineState<State>, mut mechanisms: Mechanisms<State, StandardEvent>) {
+    for _ in 0..3 {
+        if mechanisms.is_termination_requested() {
+            break;
+        }
+        mechanisms.clink_event(&mut state, StandardEvent::Tick);
+    }
+    assert!(mechanisms.clink_termination(&mut state, StandardEvent::Termination));
+    assert!(!mechanisms.clink_termination(&mut state, StandardEvent::Termination));
+    mechanisms.clink_event(&mut state, StandardEvent::Tick);
+    assert!(mechanisms.is_terminated());
+}
+
+fn run(
+    configure: impl FnOnce(
+        ClockworkBuilder<State, StandardEvent>,
+    ) -> ClockworkBuilder<State, StandardEvent>,
+) -> (ClockworkExit, Vec<StandardEvent>) {
+    let log = Log::default();
+    let exit = configure(
+        Clockwork::builder()
+            .state(State)
+            .main_loop(main_loop)
+            .add_standard_mechanism(Logger(log.clone())),
+    )
+    .build()
+    .unwrap()
+    .set_the_clock();
+    let log = log.lock().clone();
+    (exit, log)
+}
+
+#[test]
+fn requested_termination_is_clinked_once() {
+    let (exit, log) = run(|builder| builder.add_standard_mechanism(Quitter));
+    assert_eq!(
+        exit,
+        ClockworkExit {
+            exit_code: 3,
+            reason: TerminationReason::Requested {
+                reason: "Game over".into(),
+                exit_code: 3
+            }
+        }
+    );
+    assert_eq!(log, vec![StandardEvent::Tick, StandardEvent::Termination]);
+}
+
+#[test]
+fn failed_mechanisms_terminate_with_failure() {
+    let (exit, log) = run(|builder| {
+        builder
+            .add_standard_mechanism(Failing)
+            .default_error_policy(ErrorPolicy::Terminate)
+    });
+    assert!(!exit.is_success());
+    assert!(matches!(exit.reason, TerminationReason::MechanismFailed(_)));
+    assert_eq!(log, vec![StandardEvent::Tick, StandardEvent::Termination]);
+}
+
+#[test]
+fn main_loop_may_terminate_without_request() {
+    let (exit, log) = run(|builder| builder);
+    assert_eq!(exit, TerminationReason::MainLoopReturned.into());
+    assert!(exit.is_success());
+    assert_eq!(log.len(), 4);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::assert_approx_eq;
+use spc_clockwork_kernel::{
+    graphics::{scene_object_components::WorldTransform, SceneObject},
+    math::{Mat4, Quat, Transform, Vec3, Vec4},
+};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn quaternions() {
+    let quarter = Quat::from_axis_angle([0.0, 0.0, 1.0].into(), FRAC_PI_2);
+    let x: Vec3 = [1.0, 0.0, 0.0].into();
+    assert_approx_eq(quarter.rotate(x), [0.0, 1.0, 0.0].into());
+    assert_approx_eq(
+        (quarter * quarter).to_matrix(),
+        Mat4::rotation([0.0, 0.0, 1.0].into(), PI),
+    );
+    assert_approx_eq(
+        (quarter * quarter.inverse()).into(),
+        Vec4::from(Quat::identity()),
+    );
+
+    let half = Quat::identity().slerp(&(quarter * quarter), 0.5);
+    assert_approx_eq(Vec4::from(half), quarter.into());
+    let nlerp = Quat::identity().lerp(&quarter, 0.5);
+    assert!((nlerp.norm() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn transforms() {
+    let transform = Transform::new(
+        [1.0, 2.0, 3.0].into(),
+        Quat::from_axis_angle([0.0, 1.0, 0.0].into(), FRAC_PI_2),
+        [2.0, 2.0, 2.0].into(),
+    );
+    let point: Vec3 = [1.0, 0.0, 0.0].into();
+    let [x, y, z]: [f32; 3] = point.into();
+    let [px, py, pz, _]: [f32; 4] = (transform.to_matrix() * Vec4::from([x, y, z, 1.0])).into();
+    assert_approx_eq(transform.transform_point(point), [px, py, pz].into());
+    assert_approx_eq(transform.transform_point(point), [1.0, 2.0, 1.0].into());
+
+    assert_approx_eq(
+        (transform.inverse() * transform).to_matrix(),
+        Mat4::identity(),
+    );
+    assert_approx_eq(
+        (transform * transform).to_matrix(),
+        transform.to_matrix() * transform.to_matrix(),
+    );
+    assert_approx_eq(
+        transform.lerp(&Transform::identity(), 1.0).to_matrix(),
+        Mat4::identity(),
+    );
+}
+
+#[test]
+fn non_uniform_scaling() {
+    let transform = Transform::new(
+        [1.0, 0.0, 0.0].into(),
+        Quat::from_axis_angle([1.0, 1.0, 0.0].into(), 1.0),
+        [1.0, 2.0, 3.0].into(),
+    );
+    assert_approx_eq(
+        transform.inverse_matrix() * transform.to_matrix(),
+        Mat4::identity(),
+    );
+    assert_approx_eq(
+        transform.normal_matrix(),
+        transform.to_matrix().inverse().unwrap().transpose(),
+    );
+}
+
+#[test]
+fn transforms_are_scene_objects() {
+    struct Object(Transform);
+    impl WorldTransform for Object {
+        fn world_transform(&self) -> Transform {
+            self.0
+        }
+    }
+
+    let object = Object(Transform::from_translation([0.0, 0.0, 5.0].into()));
+    assert_approx_eq(
+        object.world_matrix(),
+        Mat4::translation([0.0, 0.0, 5.0].into()),
+    );
+    assert_approx_eq(
+        object.view_matrix(),
+        Mat4::translation([0.0, 0.0, -5.0].into()),
+    );
+}
@@ -0,0 +1,269 @@
+//! Derive macros for the Clockwork kernel.
+//!
+//! This crate is re-exported by the kernel, and should not be used directly.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{quote, ToTokens};
+use std::collections::HashMap;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Ident, ImplItem, Index, ItemImpl,
+    Member, Token, Type,
+};
+
+/// Implements `ClockworkState` on the struct, and `Substate<T>`, and `FieldSubstate<T>`
+/// for the type `T` of every field, delegating to this field.
+///
+/// Attributes of the fields:
+/// - `#[clockwork(skip)]` does not implement `Substate` for the field type;
+/// - `#[clockwork(substates(A, B))]` additionally implements `Substate<A>`, and `Substate<B>`,
+///   delegating to the field, which must itself implement them.
+///
+/// Every substate must be reachable through a single field,
+/// otherwise a compile error is reported.
+/// Fields of generic types may overlap with other substates,
+/// which the compiler reports as conflicting implementations.
+/// Such fields should be skipped.
+#[proc_macro_derive(ClockworkState, attributes(clockwork))]
+pub fn derive_clockwork_state(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implements `StandardMechanism::handled_events` in the impl block of the trait,
+/// returning the events, whose handlers are defined in the block.
+///
+/// If the block defines `handled_events` itself, the block is kept intact.
+#[proc_macro_attribute]
+pub fn standard_mechanism(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let attribute = TokenStream2::from(attribute);
+    let item = parse_macro_input!(item as ItemImpl);
+    match attribute.is_empty() {
+        true => expand_standard_mechanism(item),
+        false => Error::new_spanned(attribute, "Expected no arguments").into_compile_error(),
+    }
+    .into()
+}
+
+/// Handlers of the standard mechanism, alongside with their events
+const STANDARD_HANDLERS: [(&str, &str); 8] = [
+    ("initialization", "Initialization"),
+    ("fixed_tick", "FixedTick"),
+    ("tick", "Tick"),
+    ("late_tick", "LateTick"),
+    ("pre_draw", "PreDraw"),
+    ("draw", "Draw"),
+    ("post_draw", "PostDraw"),
+    ("termination", "Termination"),
+];
+
+/// Generates `handled_events` from the defined handlers
+fn expand_standard_mechanism(mut item: ItemImpl) -> TokenStream2 {
+    let kernel = kernel_path();
+    let defined = item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Method(method) => Some(method.sig.ident.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !defined.iter().any(|name| name == "handled_events") {
+        let events = STANDARD_HANDLERS
+            .iter()
+            .filter(|(handler, _)| defined.iter().any(|name| name == handler))
+            .map(|(_, event)| Ident::new(event, Span::call_site()));
+        item.items.push(syn::parse_quote! {
+            fn handled_events(
+                &self,
+            ) -> ::std::option::Option<::std::vec::Vec<#kernel::standard_runtime::StandardEvent>> {
+                ::std::option::Option::Some(::std::vec![
+                    #(#kernel::standard_runtime::StandardEvent::#events),*
+                ])
+            }
+        });
+    }
+    item.into_token_stream()
+}
+
+/// A field, through which substates are reachable
+struct SubstateField {
+    /// The field accessor
+    member: Member,
+
+    /// Whether the field is not a substate itself
+    skip: bool,
+
+    /// Nested substates of the field
+    substates: Vec<Type>,
+}
+
+/// Generates the implementations
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let kernel = kernel_path();
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "ClockworkState can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut paths = HashMap::<String, Member>::new();
+    let mut substate_impls = Vec::new();
+    let mut field_impls = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let SubstateField {
+            member,
+            skip,
+            substates,
+        } = parse_field_attributes(member, &field.attrs)?;
+        let field_type = &field.ty;
+        if !skip {
+            field_impls.push(quote! {
+                unsafe impl #impl_generics #kernel::abstract_runtime::FieldSubstate<#field_type>
+                    for #name #type_generics #where_clause
+                {
+                    unsafe fn field(state: *mut Self) -> *mut #field_type {
+                        ::core::ptr::addr_of_mut!((*state).#member)
+                    }
+                }
+            });
+        }
+        let own = (!skip).then(|| {
+            (
+                field_type.clone(),
+                quote!(callback(&self.#member)),
+                quote!(callback(&mut self.#member)),
+            )
+        });
+        let nested = substates.into_iter().map(|substate| {
+            (
+                substate,
+                quote!(#kernel::abstract_runtime::Substate::substate(&self.#member, callback)),
+                quote!(#kernel::abstract_runtime::Substate::substate_mut(&mut self.#member, callback)),
+            )
+        });
+        for (substate, get, get_mut) in own.into_iter().chain(nested) {
+            let key = type_key(&substate);
+            if let Some(first) = paths.insert(key.clone(), member.clone()) {
+                return Err(Error::new_spanned(
+                    &substate,
+                    format!(
+                        "Substate `{}` is reachable through both `{}`, and `{}`",
+                        key,
+                        member_name(&first),
+                        member_name(&member),
+                    ),
+                ));
+            }
+            substate_impls.push(quote! {
+                impl #impl_generics #kernel::abstract_runtime::Substate<#substate>
+                    for #name #type_generics #where_clause
+                {
+                    fn substate<R>(&self, callback: impl FnOnce(&#substate) -> R) -> R {
+                        #get
+                    }
+
+                    fn substate_mut<R>(&mut self, callback: impl FnOnce(&mut #substate) -> R) -> R {
+                        #get_mut
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics #kernel::abstract_runtime::ClockworkState
+            for #name #type_generics #where_clause {}
+
+        #(#substate_impls)*
+
+        #(#field_impls)*
+    })
+}
+
+/// Parses the `#[clockwork(...)]` attributes of the field
+fn parse_field_attributes(
+    member: Member,
+    attributes: &[Attribute],
+) -> Result<SubstateField, Error> {
+    let mut field = SubstateField {
+        member,
+        skip: false,
+        substates: Vec::new(),
+    };
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("clockwork"))
+    {
+        // Types are not valid meta items, so the arguments are parsed by hand
+        attribute.parse_args_with(|input: ParseStream| {
+            while !input.is_empty() {
+                let argument: Ident = input.parse()?;
+                match argument.to_string().as_str() {
+                    "skip" => field.skip = true,
+                    "substates" => {
+                        let types;
+                        parenthesized!(types in input);
+                        field
+                            .substates
+                            .extend(types.parse_terminated::<_, Token![,]>(Type::parse)?)
+                    }
+                    _ => {
+                        return Err(Error::new_spanned(
+                            argument,
+                            "Expected `skip`, or `substates(...)`",
+                        ))
+                    }
+                }
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+            Ok(())
+        })?
+    }
+    Ok(field)
+}
+
+/// Gets the path to the kernel crate, which may be renamed by the user
+fn kernel_path() -> TokenStream2 {
+    match crate_name("spc_clockwork_kernel") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = Ident::new(&name, Span::call_site());
+            quote!(::#name)
+        }
+        _ => quote!(::spc_clockwork_kernel),
+    }
+}
+
+/// Gets a comparable representation of the type
+fn type_key(substate: &Type) -> String {
+    substate
+        .to_token_stream()
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Gets the name of the field for the error messages
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
@@ -1,6 +1,7 @@
 /* ---- PRELUDE ---- */
 pub mod prelude {
     pub use crate::mechanism::LegionSystems;
+    pub use crate::plugin::LegionPlugin;
     pub use crate::state::LegionState;
     pub use legion::*;
 }
@@ -9,6 +10,9 @@ pub mod prelude {
 /// Mechanism description
 pub mod mechanism;
 
+/// Plugin description
+pub mod plugin;
+
 /// State description
 pub mod state;
 
@@ -11,19 +11,41 @@ use std::collections::HashMap;
 /// of Legion ECS, provided by LegionState through Systems, grouped by Schedules
 /// -- one per event type.
 ///
-/// During every handled event, performs a call to the schedule, which does
+/// During every handled event, performs a call to the schedule of its kind, which does
 /// some reading and writing to the world and its resources through queries.
 ///
 /// The World, in this case, may be seen as a database.
 ///
 /// The ECS option should be considered primary, when it comes to application
 /// dynamic behavior control.
+///
+/// In order to run the schedules only in certain game states,
+/// gate the mechanism with `GameStateMechanismExt::in_game_states`:
+/// ```
+/// # use kernel::{abstract_runtime::ClockworkState, prelude::*, standard_runtime::*};
+/// # use spc_clockwork_legion_ecs::prelude::*;
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Mode {
+///     Playing,
+///     Paused,
+/// }
+///
+/// #[derive(ClockworkState)]
+/// struct State {
+///     ecs: LegionState,
+///     mode: GameState<Mode>,
+/// }
+///
+/// let systems = LegionSystems::<StandardEvent>::builder().build().unwrap();
+/// let builder = Clockwork::<State, StandardEvent>::builder()
+///     .add_mechanism(systems.in_game_states(vec![Mode::Playing]));
+/// ```
 pub struct LegionSystems<E>
 where
     E: ClockworkEvent,
 {
-    /// A mapping from events to system collections, i.e. schedules.
-    events_to_schedules: HashMap<E, Schedule>,
+    /// A mapping from event kinds to system collections, i.e. schedules.
+    events_to_schedules: HashMap<E::Kind, Schedule>,
 }
 
 impl<E> LegionSystems<E>
@@ -39,16 +61,16 @@ pub struct LegionSystemsBuilder<E>
 where
     E: ClockworkEvent,
 {
-    /// A mapping from events to system collections, i.e. schedules.
-    events_to_schedule_builders: HashMap<E, systems::Builder>,
+    /// A mapping from event kinds to system collections, i.e. schedules.
+    events_to_schedule_builders: HashMap<E::Kind, systems::Builder>,
 }
 
 impl<E> LegionSystemsBuilder<E>
 where
     E: ClockworkEvent,
 {
-    /// Adds a system to the schedule, executed on this event type.
-    pub fn add_system(mut self, event: E, system: impl ParallelRunnable + 'static) -> Self {
+    /// Adds a system to the schedule, executed on this event kind.
+    pub fn add_system(mut self, event: E::Kind, system: impl ParallelRunnable + 'static) -> Self {
         self.events_to_schedule_builders
             .entry(event)
             .or_default()
@@ -93,13 +115,13 @@ where
             .start_mutate()
             .get_mut(|LegionState { world, resources }| {
                 self.events_to_schedules
-                    .get_mut(&event)
+                    .get_mut(&event.kind())
                     .map_or((), |schedule| schedule.execute(world, resources))
             })
             .finish()
     }
 
-    fn handled_events(&self) -> Option<Vec<E>> {
+    fn handled_events(&self) -> Option<Vec<E::Kind>> {
         Some(self.events_to_schedules.keys().cloned().collect())
     }
 }
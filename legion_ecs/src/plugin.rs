@@ -0,0 +1,72 @@
+use crate::{
+    mechanism::{LegionSystems, LegionSystemsBuilder},
+    state::LegionState,
+};
+use kernel::{
+    abstract_runtime::{ClockworkEvent, MechanismOrdering, Substate},
+    clockwork::{ClockworkBuilder, ClockworkBuilderError},
+    plugin::Plugin,
+};
+use legion::systems::ParallelRunnable;
+
+/// A plugin, which runs the systems of Legion ECS upon their events.
+///
+/// The systems mechanism is labeled as "ecs".
+pub struct LegionPlugin<E>
+where
+    E: ClockworkEvent,
+{
+    /// The builder of the systems mechanism
+    systems: LegionSystemsBuilder<E>,
+
+    /// The amount of added systems
+    system_count: usize,
+}
+
+impl<E> Default for LegionPlugin<E>
+where
+    E: ClockworkEvent,
+{
+    fn default() -> Self {
+        Self {
+            systems: LegionSystems::builder(),
+            system_count: 0,
+        }
+    }
+}
+
+impl<E> LegionPlugin<E>
+where
+    E: ClockworkEvent,
+{
+    /// Adds a system to the schedule, executed on this event kind.
+    pub fn add_system(self, event: E::Kind, system: impl ParallelRunnable + 'static) -> Self {
+        Self {
+            systems: self.systems.add_system(event, system),
+            system_count: self.system_count + 1,
+        }
+    }
+}
+
+impl<S, E> Plugin<S, E> for LegionPlugin<E>
+where
+    S: Substate<LegionState>,
+    E: ClockworkEvent,
+{
+    fn validate(&self) -> Result<(), String> {
+        match self.system_count {
+            0 => Err("No systems have been added".into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn install(
+        self,
+        builder: ClockworkBuilder<S, E>,
+    ) -> Result<ClockworkBuilder<S, E>, ClockworkBuilderError> {
+        let systems = self.systems.build().map_err(|()| {
+            ClockworkBuilderError::ValidationError("Failed to build the Legion systems".into())
+        })?;
+        Ok(builder.add_ordered_mechanism(systems, MechanismOrdering::labeled("ecs")))
+    }
+}
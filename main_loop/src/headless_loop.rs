@@ -0,0 +1,176 @@
+use crate::state::{FixedTimestep, MainLoopStatistics};
+use derive_builder::Builder;
+use kernel::abstract_runtime::{EngineState, Mechanisms, SessionTiming, Substate};
+use kernel::prelude::*;
+use kernel::standard_runtime::{StandardEventSuperset, StandardRuntimeStatistics};
+use std::time::{Duration, Instant};
+
+/// A clock, which drives the headless main loop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeadlessClock {
+    /// The time advances instantly from one event to the next one,
+    /// so the loop runs as fast as possible, and is fully deterministic
+    Virtual,
+
+    /// The loop sleeps until the scheduled time of the next event
+    Real,
+}
+
+/// A main loop, which runs the engine without a window.
+///
+/// Emits `Initialization`, then the ticks (`FixedTick`, `Tick`, and `LateTick`),
+/// and the frames (`PreDraw`, `Draw`, and `PostDraw`) according to the desired periods
+/// of `MainLoopStatistics`, and finally `Termination`.
+/// A zero draw period is treated as one frame per tick.
+/// The loop finishes, once the maximum amount of ticks has passed,
+/// or a mechanism has requested the termination. The rest of the events
+/// of the tick, or the frame, during which the termination has been requested, are not emitted.
+///
+/// Example:
+/// ```
+/// # use kernel::{abstract_runtime::ClockworkState, prelude::*};
+/// # use spc_clockwork_main_loop::prelude::*;
+/// #[derive(ClockworkState)]
+/// struct State {
+///     statistics: MainLoopStatistics,
+/// }
+///
+/// let headless = HeadlessLoop::builder().max_ticks(600).build().unwrap();
+/// let exit = Clockwork::<State, StandardEvent>::builder()
+///     .state(State {
+///         statistics: MainLoopStatistics::builder().build().unwrap(),
+///     })
+///     .main_loop(move |state, mechanisms| headless.run(state, mechanisms))
+///     .on(StandardEvent::Tick, |_| trace!("Tick"))
+///     .build()
+///     .unwrap()
+///     .set_the_clock();
+/// assert!(exit.is_success());
+/// ```
+#[derive(Builder, Clone, Copy, Debug)]
+#[builder(pattern = "owned")]
+pub struct HeadlessLoop {
+    /// The clock of the loop
+    #[builder(default = "HeadlessClock::Virtual")]
+    clock: HeadlessClock,
+
+    /// The amount of ticks, after which the loop terminates.
+    ///
+    /// If None, then the loop runs until the termination is requested.
+    #[builder(default, setter(strip_option))]
+    max_ticks: Option<u64>,
+
+    /// Whether `Draw` events are emitted
+    #[builder(default = "true")]
+    draw: bool,
+}
+
+impl HeadlessLoop {
+    /// Creates the builder of the loop, which runs with the virtual clock,
+    /// emits the draw calls, and runs until the termination is requested by default.
+    pub fn builder() -> HeadlessLoopBuilder {
+        Default::default()
+    }
+
+    /// Runs the engine until the maximum amount of ticks has passed,
+    /// or the termination is requested.
+    pub fn run<S, E>(self, mut state: EngineState<S>, mut mechanisms: Mechanisms<S, E>)
+    where
+        S: Substate<MainLoopStatistics>,
+        E: StandardEventSuperset,
+    {
+        /* -- INITIALIZING MECHANISMS -- */
+        info!("Initializing mechanisms");
+        mechanisms.clink_event(&mut state, StandardEvent::Initialization.into());
+        info!("Finished initializing mechanisms");
+
+        /* ---- HEADLESS LOOP LAUNCH ---- */
+        info!("Starting headless main loop with {:?} clock", self.clock);
+        let start = Instant::now();
+        let mut next_tick_at = Duration::default();
+        let mut next_draw_at = Duration::default();
+        let mut last_tick_at = None;
+        let mut last_draw_at = None;
+        let mut timing = SessionTiming::default();
+        let mut fixed_timestep = FixedTimestep::default();
+
+        while !matches!(self.max_ticks, Some(max) if timing.ticks_total >= max)
+            && !mechanisms.is_termination_requested()
+        {
+            let (desired_tick_period, desired_min_draw_period, fixed_tick_delta) = state
+                .start_access()
+                .get(|statistics: &MainLoopStatistics| {
+                    (
+                        statistics.desired_avg_tick_period,
+                        statistics.desired_min_draw_period,
+                        statistics.fixed_tick_delta(),
+                    )
+                })
+                .finish();
+
+            /* ---- WAITING FOR THE NEXT EVENT ---- */
+            let is_tick = !self.draw || next_tick_at <= next_draw_at;
+            let now = self.wait_until(start, if is_tick { next_tick_at } else { next_draw_at });
+            let events: Vec<StandardEvent> = if is_tick {
+                timing.tick_delta =
+                    last_tick_at.map_or(desired_tick_period, |last| now.saturating_sub(last));
+                timing.ticks_total += 1;
+                last_tick_at = Some(now);
+                next_tick_at += desired_tick_period;
+                let fixed_ticks = fixed_timestep.advance(timing.tick_delta, fixed_tick_delta);
+                (0..fixed_ticks)
+                    .map(|_| StandardEvent::FixedTick)
+                    .chain(StandardEvent::TICK_PHASES)
+                    .collect()
+            } else {
+                timing.draw_delta =
+                    last_draw_at.map_or(desired_min_draw_period, |last| now.saturating_sub(last));
+                timing.frames_total += 1;
+                last_draw_at = Some(now);
+                // An uncapped frame rate would never let the next tick come first
+                next_draw_at = match desired_min_draw_period.is_zero() {
+                    true => next_tick_at,
+                    false => now + desired_min_draw_period,
+                };
+                StandardEvent::DRAW_PHASES.to_vec()
+            };
+
+            /* ---- HANDLING EVENTS ---- */
+            state
+                .start_mutate()
+                .get_mut(|statistics: &mut MainLoopStatistics| statistics.apply_timing(timing))
+                .finish();
+            for event in events {
+                if mechanisms.is_termination_requested() {
+                    debug!("Skipping headless event {:?}: termination requested", event);
+                    break;
+                }
+                trace!("Handling headless event: {:?}", event);
+                mechanisms.clink_event(&mut state, event.into());
+            }
+        }
+
+        /* ---- TERMINATION ---- */
+        debug!("Terminating mechanisms");
+        mechanisms.clink_termination(&mut state, StandardEvent::Termination.into());
+        info!(
+            "Finished headless main loop after {} ticks, and {} frames",
+            timing.ticks_total, timing.frames_total
+        );
+    }
+
+    /// Waits until the scheduled time, and returns the current time
+    /// relative to the start of the loop
+    fn wait_until(&self, start: Instant, scheduled: Duration) -> Duration {
+        match self.clock {
+            HeadlessClock::Virtual => scheduled,
+            HeadlessClock::Real => {
+                let elapsed = start.elapsed();
+                if scheduled > elapsed {
+                    std::thread::sleep(scheduled - elapsed)
+                }
+                start.elapsed()
+            }
+        }
+    }
+}
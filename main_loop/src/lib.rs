@@ -1,3 +1,4 @@
+pub mod headless_loop;
 pub mod main_loop;
 pub mod state {
     /* ---- PRIVATE ---- */
@@ -12,6 +13,7 @@ pub mod state {
 }
 
 pub mod prelude {
+    pub use crate::headless_loop::*;
     pub use crate::main_loop::*;
     pub use crate::state::*;
     pub use winit::event::{Event, VirtualKeyCode, WindowEvent};
@@ -1,17 +1,77 @@
 use crate::state::InitWinitState;
-use crate::state::{InputState, MainLoopStatistics};
-use kernel::abstract_runtime::{EngineState, Mechanisms, Substate};
+use crate::state::{FixedTimestep, InputChange, InputState, MainLoopStatistics};
+use kernel::abstract_runtime::{
+    EngineState, Mechanisms, SessionEntry, SessionRecording, SessionTiming, Substate,
+    TerminationReason,
+};
 use kernel::prelude::*;
-use kernel::standard_runtime::StandardEventSuperset;
+use kernel::standard_runtime::{StandardEventSuperset, StandardRuntimeStatistics};
 use std::convert::TryInto;
 use std::*;
 use winit::{
     event::{Event as WinitEvent, KeyboardInput, WindowEvent},
     event_loop::ControlFlow,
+    platform::run_return::EventLoopExtRunReturn,
 };
 
-pub fn main_loop<S, E>(mut state: EngineState<S>, mut mechanisms: Mechanisms<S, E>)
+/// A function, which records the input changes, and the timings
+/// into the `SessionRecording` substate
+type SessionRecorder<S, E> = fn(&mut EngineState<S>, SessionEntry<E, InputChange>);
+
+/// The main loop, which runs the engine in a winit window.
+///
+/// Every tick is emitted as zero, or more `FixedTick` events, followed by `Tick`, and `LateTick`,
+/// and every frame is emitted as `PreDraw`, `Draw`, and `PostDraw`.
+///
+/// Closing the window requests the termination with `TerminationReason::WindowClosed`.
+/// The loop returns after the `Termination` event has been clinked, and gives the event loop
+/// back to the `InitWinitState`, which becomes uninitialized again.
+pub fn main_loop<S, E>(state: EngineState<S>, mechanisms: Mechanisms<S, E>)
+where
+    S: Substate<MainLoopStatistics> + Substate<InitWinitState<E>> + Substate<InputState>,
+    E: StandardEventSuperset,
+{
+    winit_loop(state, mechanisms, None)
+}
+
+/// The main loop, which runs the engine in a winit window, like `main_loop`,
+/// and records the session for a later replay with `replay_loop`.
+///
+/// Keyboard input changes, and timings are recorded into the `SessionRecording`
+/// substate, while it is started. The events are recorded by the mechanisms,
+/// if the recording has been enabled with `ClockworkBuilder::record_session`.
+pub fn recording_main_loop<S, E>(state: EngineState<S>, mechanisms: Mechanisms<S, E>)
 where
+    S: Substate<MainLoopStatistics>
+        + Substate<InitWinitState<E>>
+        + Substate<InputState>
+        + Substate<SessionRecording<E, InputChange>>,
+    E: StandardEventSuperset,
+{
+    winit_loop(
+        state,
+        mechanisms,
+        Some(|state, entry| {
+            state
+                .start_mutate()
+                .get_mut(
+                    |recording: &mut SessionRecording<E, InputChange>| match entry {
+                        SessionEntry::Event(event) => recording.record_event(&event),
+                        SessionEntry::Timing(timing) => recording.record_timing(timing),
+                        SessionEntry::Input(change) => recording.record_input(change),
+                    },
+                )
+                .finish()
+        }),
+    )
+}
+
+/// Runs the winit event loop, passing the input changes, and the timings to the recorder
+fn winit_loop<S, E>(
+    mut state: EngineState<S>,
+    mut mechanisms: Mechanisms<S, E>,
+    recorder: Option<SessionRecorder<S, E>>,
+) where
     S: Substate<MainLoopStatistics> + Substate<InitWinitState<E>> + Substate<InputState>,
     E: StandardEventSuperset,
 {
@@ -23,7 +83,7 @@ where
 
     /* -- TAKING BACK EVENT LOOP OBJECT FROM THE STATE -- */
     info!("Retrieving event loop object from the engine state");
-    let (event_loop, event_proxy) = state
+    let (mut event_loop, event_proxy) = state
         .start_mutate()
         .get_mut(|s: &mut InitWinitState<E>| s.initialize())
         .finish();
@@ -40,8 +100,10 @@ where
     let mut draw_debt = 0f32;
     let mut ticks_total = 0;
     let mut frames_total = 0;
+    let mut fixed_timestep = FixedTimestep::default();
+    let mut termination_sent = false;
 
-    event_loop.run(move |ev, _, cf| {
+    event_loop.run_return(|ev, _, cf| {
         trace!("Handling next event: {:?}", ev);
         let current_time = time::Instant::now();
 
@@ -62,7 +124,7 @@ where
             {
                 debug!("Requested main loop termination");
                 debug!("Terminating mechanisms");
-                mechanisms.clink_event(&mut state, event.clone());
+                mechanisms.clink_termination(&mut state, event.clone());
                 debug!("Terminating main loop");
                 *cf = ControlFlow::Exit;
                 debug!("Finished main loop termination");
@@ -78,6 +140,13 @@ where
                     debug!("Finished handling custom event: {:?}", &event);
                 }
             }
+            WinitEvent::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                ..
+            } => {
+                debug!("Window close requested");
+                mechanisms.request_termination(TerminationReason::WindowClosed)
+            }
             WinitEvent::WindowEvent {
                 event:
                     WindowEvent::KeyboardInput {
@@ -91,13 +160,17 @@ where
                     },
                 ..
             } => {
+                let change = match keyboard_state {
+                    winit::event::ElementState::Pressed => InputChange::Pressed(vkk),
+                    winit::event::ElementState::Released => InputChange::Released(vkk),
+                };
                 state
                     .start_mutate()
-                    .get_mut(|InputState { pressed_keys, .. }| match keyboard_state {
-                        winit::event::ElementState::Pressed => pressed_keys.insert(vkk),
-                        winit::event::ElementState::Released => pressed_keys.remove(&vkk),
-                    })
+                    .get_mut(|input: &mut InputState| input.apply(change))
                     .finish();
+                if let Some(record) = recorder {
+                    record(&mut state, SessionEntry::Input(change))
+                }
             }
             WinitEvent::MainEventsCleared => {
                 let (desired_tick_period, desired_min_draw_period) = state
@@ -122,18 +195,26 @@ where
                         ticks_total += 1;
                         last_tick_start_at = time::Instant::now();
                         tick_debt -= 1f32;
-                        event_proxy
-                            .send_event(StandardEvent::Tick.into())
-                            .map_or((), |_| ())
+                        let fixed_tick_delta = state
+                            .start_access()
+                            .get(MainLoopStatistics::fixed_tick_delta)
+                            .finish();
+                        let fixed_ticks = fixed_timestep.advance(tick_delta, fixed_tick_delta);
+                        (0..fixed_ticks)
+                            .map(|_| StandardEvent::FixedTick)
+                            .chain(StandardEvent::TICK_PHASES)
+                            .for_each(|event| {
+                                event_proxy.send_event(event.into()).map_or((), |_| ())
+                            })
                     }
                     (_, draw_delta) if draw_debt >= 1f32 => {
                         est_draw_period = draw_delta;
                         frames_total += 1;
                         draw_debt = 0f32;
                         last_draw_start_at = time::Instant::now();
-                        event_proxy
-                            .send_event(StandardEvent::Draw.into())
-                            .map_or((), |_| ())
+                        StandardEvent::DRAW_PHASES.iter().for_each(|&event| {
+                            event_proxy.send_event(event.into()).map_or((), |_| ())
+                        })
                     }
                     (tick_delta, draw_delta) => {
                         tick_debt += tick_delta.as_secs_f32() / desired_tick_period.as_secs_f32();
@@ -150,7 +231,7 @@ where
                     }
                 }
 
-                state
+                let timing = state
                     .start_mutate()
                     .get_mut(
                         |MainLoopStatistics {
@@ -160,16 +241,95 @@ where
                              frames_total: state_frames_total,
                              ..
                          }| {
+                            let changed = *state_ticks_total != ticks_total
+                                || *state_frames_total != frames_total;
                             *state_ticks_total = ticks_total;
                             *state_frames_total = frames_total;
                             *state_tick_period = est_tick_period;
                             *state_draw_period = est_draw_period;
+                            changed.then_some(SessionTiming {
+                                tick_delta: est_tick_period,
+                                draw_delta: est_draw_period,
+                                ticks_total,
+                                frames_total,
+                            })
                         },
                     )
-                    .finish()
+                    .finish();
+                if let (Some(record), Some(timing)) = (recorder, timing) {
+                    record(&mut state, SessionEntry::Timing(timing))
+                }
             }
             _ => {}
         };
+
+        /* ---- HANDLING TERMINATION REQUESTS ---- */
+        if mechanisms.is_termination_requested() && !termination_sent {
+            info!("Termination requested");
+            termination_sent = true;
+            event_proxy
+                .send_event(StandardEvent::Termination.into())
+                .map_or((), |_| ())
+        }
         trace!("Finished handling event: {:?}", ev);
     });
+
+    /* ---- TERMINATION OF THE EXITED EVENT LOOP ---- */
+    if mechanisms.clink_termination(&mut state, StandardEvent::Termination.into()) {
+        warn!("Event loop exited before the termination");
+    }
+
+    /* ---- RETURNING EVENT LOOP OBJECT TO THE STATE ---- */
+    state
+        .start_mutate()
+        .get_mut(|s: &mut InitWinitState<E>| s.restart(event_loop))
+        .finish();
+    info!("Finished main loop");
+}
+
+/// The main loop, which replays the recorded session without a window.
+///
+/// Recorded events are passed to the mechanisms, while recorded input changes,
+/// and timings are applied to `InputState`, and `MainLoopStatistics`
+/// at the same points of the session, so that the mechanisms observe
+/// exactly the same state, as during the recording.
+///
+/// The loop finishes after the last entry, or after the `Termination` event is replayed.
+/// If the recording ends without the `Termination` event, it is clinked after the last entry.
+pub fn replay_loop<S, E>(
+    mut state: EngineState<S>,
+    mut mechanisms: Mechanisms<S, E>,
+    entries: Vec<SessionEntry<E, InputChange>>,
+) where
+    S: Substate<MainLoopStatistics> + Substate<InputState>,
+    E: StandardEventSuperset,
+{
+    info!("Replaying {} recorded entries", entries.len());
+    for entry in entries {
+        trace!("Replaying entry: {:?}", entry);
+        match entry {
+            SessionEntry::Event(event) => {
+                let termination = matches!(
+                    TryInto::<StandardEvent>::try_into(event.clone()),
+                    Ok(StandardEvent::Termination)
+                );
+                if termination {
+                    debug!("Replayed main loop termination");
+                    mechanisms.clink_termination(&mut state, event);
+                    break;
+                }
+                mechanisms.clink_event(&mut state, event);
+            }
+            SessionEntry::Timing(timing) => state
+                .start_mutate()
+                .get_mut(|statistics: &mut MainLoopStatistics| statistics.apply_timing(timing))
+                .finish(),
+            SessionEntry::Input(change) => state
+                .start_mutate()
+                .get_mut(|input: &mut InputState| input.apply(change))
+                .finish(),
+        }
+    }
+    mechanisms.clink_termination(&mut state, StandardEvent::Termination.into());
+    info!("Finished replaying the session");
 }
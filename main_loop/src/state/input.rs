@@ -1,5 +1,6 @@
 use kernel::{
     abstract_runtime::ClockworkState,
+    prelude::{Deserialize, Serialize},
     util::{derive_builder::Builder, getset::Getters},
 };
 use std::collections::HashSet;
@@ -17,8 +18,27 @@ pub struct InputState {
 
 impl ClockworkState for InputState {}
 
+/// A change of the input state,
+/// which is recorded into the session recording
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputChange {
+    /// The key has been pressed
+    Pressed(VirtualKeyCode),
+
+    /// The key has been released
+    Released(VirtualKeyCode),
+}
+
 impl InputState {
     pub fn builder() -> InputStateBuilder {
         Default::default()
     }
+
+    /// Applies the change to the input state
+    pub(crate) fn apply(&mut self, change: InputChange) {
+        match change {
+            InputChange::Pressed(key) => self.pressed_keys.insert(key),
+            InputChange::Released(key) => self.pressed_keys.remove(&key),
+        };
+    }
 }
@@ -1,5 +1,5 @@
 use kernel::{
-    abstract_runtime::ClockworkState,
+    abstract_runtime::{ClockworkState, SessionTiming},
     standard_runtime::StandardRuntimeStatistics,
     util::{derive_builder::Builder, getset::Setters},
 };
@@ -50,6 +50,48 @@ impl MainLoopStatistics {
     pub fn builder() -> MainLoopStatisticsBuilder {
         Default::default()
     }
+
+    /// Applies the recorded timing to the statistics
+    pub(crate) fn apply_timing(&mut self, timing: SessionTiming) {
+        self.current_tick_delta = timing.tick_delta;
+        self.current_draw_delta = timing.draw_delta;
+        self.ticks_total = timing.ticks_total;
+        self.frames_total = timing.frames_total;
+    }
+}
+
+/// An accumulator of the elapsed tick time, which is spent in fixed steps.
+///
+/// This is crate-private, and is used by the main loops to decide,
+/// how many `FixedTick` events precede the `Tick`.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct FixedTimestep(time::Duration);
+
+impl FixedTimestep {
+    /// The maximum amount of fixed steps per tick.
+    ///
+    /// The time, which exceeds this amount, is dropped, so that a long stall
+    /// does not make the engine catch up for a long time.
+    const MAX_STEPS_PER_TICK: u32 = 8;
+
+    /// Accumulates the tick delta, and returns the amount of fixed steps,
+    /// which fit into the accumulated time.
+    pub(crate) fn advance(&mut self, tick_delta: time::Duration, step: time::Duration) -> u32 {
+        if step.is_zero() {
+            return 1;
+        }
+        self.0 += tick_delta;
+        let mut steps = 0;
+        while self.0 >= step {
+            if steps == Self::MAX_STEPS_PER_TICK {
+                self.0 = Default::default();
+                break;
+            }
+            self.0 -= step;
+            steps += 1;
+        }
+        steps
+    }
 }
 
 impl ClockworkState for MainLoopStatistics {}
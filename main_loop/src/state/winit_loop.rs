@@ -125,6 +125,20 @@ where
         });
         ep.unwrap()
     }
+
+    /// Restarts the state with the event loop, which has been returned by the main loop.
+    ///
+    /// The event callbacks are dropped, so the state can be reused by another engine run,
+    /// whose mechanisms subscribe again upon their initialization.
+    ///
+    /// This method is crate-private.
+    pub(crate) fn restart(&mut self, event_loop: EventLoop<E>) {
+        let proxy = WinitLoopProxy {
+            event_loop_proxy: event_loop.create_proxy().into(),
+            callbacks: Vec::default().into(),
+        };
+        self.inner.restart(|_| (event_loop, proxy))
+    }
 }
 
 impl<E> ClockworkState for InitWinitState<E> where E: ClockworkEvent {}
@@ -0,0 +1,158 @@
+use kernel::{
+    abstract_runtime::{ClockworkExit, ClockworkState, EngineState, Mechanism, TerminationReason},
+    prelude::*,
+    standard_runtime::StandardRuntimeStatistics,
+    util::sync::WriteLock,
+};
+use spc_clockwork_main_loop::prelude::*;
+use std::time::Duration;
+
+#[derive(ClockworkState)]
+struct State {
+    statistics: MainLoopStatistics,
+}
+
+type Log = WriteLock<Vec<StandardEvent>>;
+
+/// Logs every event, and requests the termination upon the tick with the given number
+struct Logger(Log, Option<u64>);
+
+impl Mechanism<State, StandardEvent> for Logger {
+    fn clink(&mut self, state: &mut EngineState<State>, event: StandardEvent) {
+        self.0.lock_mut().push(event);
+        let ticks = state
+            .start_access()
+            .get(|statistics: &MainLoopStatistics| statistics.ticks_total())
+            .finish();
+        if event == StandardEvent::Tick && Some(ticks) == self.1 {
+            state.request_termination("Enough ticks")
+        }
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        None
+    }
+}
+
+fn run(
+    headless: HeadlessLoop,
+    statistics: MainLoopStatisticsBuilder,
+    terminate_at: Option<u64>,
+) -> (Vec<StandardEvent>, ClockworkExit) {
+    let log = Log::default();
+    let exit = Clockwork::<State, StandardEvent>::builder()
+        .state(State {
+            statistics: statistics.build().unwrap(),
+        })
+        .main_loop(move |state, mechanisms| headless.run(state, mechanisms))
+        .add_mechanism(Logger(log.clone(), terminate_at))
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let log = log.lock().clone();
+    (log, exit)
+}
+
+#[test]
+fn runs_the_given_amount_of_ticks() {
+    use StandardEvent::*;
+    let headless = HeadlessLoop::builder()
+        .max_ticks(2)
+        .draw(false)
+        .build()
+        .unwrap();
+    let (log, exit) = run(headless, MainLoopStatistics::builder(), None);
+    assert_eq!(
+        log,
+        vec![
+            Initialization,
+            FixedTick,
+            Tick,
+            LateTick,
+            FixedTick,
+            Tick,
+            LateTick,
+            Termination
+        ]
+    );
+    assert_eq!(exit.reason, TerminationReason::MainLoopReturned);
+}
+
+#[test]
+fn frames_are_interleaved_with_ticks() {
+    use StandardEvent::*;
+    let headless = HeadlessLoop::builder().max_ticks(2).build().unwrap();
+    let (log, _) = run(headless, MainLoopStatistics::builder(), None);
+    assert_eq!(
+        log,
+        vec![
+            Initialization,
+            FixedTick,
+            Tick,
+            LateTick,
+            PreDraw,
+            Draw,
+            PostDraw,
+            FixedTick,
+            Tick,
+            LateTick,
+            Termination
+        ]
+    );
+}
+
+#[test]
+fn termination_stops_the_dispatch_of_the_tick() {
+    use StandardEvent::*;
+    let headless = HeadlessLoop::builder().draw(false).build().unwrap();
+    let (log, exit) = run(headless, MainLoopStatistics::builder(), Some(2));
+    assert_eq!(
+        log,
+        vec![
+            Initialization,
+            FixedTick,
+            Tick,
+            LateTick,
+            FixedTick,
+            Tick,
+            Termination
+        ]
+    );
+    assert_eq!(
+        exit.reason,
+        TerminationReason::Requested {
+            reason: "Enough ticks".into(),
+            exit_code: 0
+        }
+    );
+}
+
+#[test]
+fn zero_draw_period_draws_once_per_tick() {
+    use StandardEvent::*;
+    let headless = HeadlessLoop::builder().max_ticks(3).build().unwrap();
+    let statistics = MainLoopStatistics::builder().desired_min_draw_period(Duration::ZERO);
+    let (log, _) = run(headless, statistics, None);
+    assert_eq!(
+        log,
+        vec![
+            Initialization,
+            FixedTick,
+            Tick,
+            LateTick,
+            PreDraw,
+            Draw,
+            PostDraw,
+            FixedTick,
+            Tick,
+            LateTick,
+            PreDraw,
+            Draw,
+            PostDraw,
+            FixedTick,
+            Tick,
+            LateTick,
+            Termination
+        ]
+    );
+}
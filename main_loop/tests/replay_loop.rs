@@ -0,0 +1,103 @@
+use kernel::{
+    abstract_runtime::{ClockworkState, EngineState, Mechanism, SessionEntry, SessionTiming},
+    prelude::*,
+    standard_runtime::StandardRuntimeStatistics,
+    util::sync::WriteLock,
+};
+use spc_clockwork_main_loop::prelude::*;
+use std::time::Duration;
+
+#[derive(ClockworkState)]
+struct State {
+    statistics: MainLoopStatistics,
+    input: InputState,
+}
+
+/// The event, the amount of ticks, and whether W has been pressed, as observed by a mechanism
+type Log = WriteLock<Vec<(StandardEvent, u64, bool)>>;
+
+struct Observer(Log);
+
+impl Mechanism<State, StandardEvent> for Observer {
+    fn clink(&mut self, state: &mut EngineState<State>, event: StandardEvent) {
+        let observed = state
+            .start_access()
+            .get(|statistics: &MainLoopStatistics| statistics.ticks_total())
+            .then_get_zip(|input: &InputState| input.pressed_keys().contains(&VirtualKeyCode::W))
+            .finish();
+        self.0.lock_mut().push((event, observed.0, observed.1))
+    }
+
+    fn handled_events(&self) -> Option<Vec<StandardEvent>> {
+        None
+    }
+}
+
+fn replay(
+    entries: Vec<SessionEntry<StandardEvent, InputChange>>,
+) -> Vec<(StandardEvent, u64, bool)> {
+    let log = Log::default();
+    Clockwork::<State, StandardEvent>::builder()
+        .state(State {
+            statistics: MainLoopStatistics::builder().build().unwrap(),
+            input: InputState::builder().build().unwrap(),
+        })
+        .main_loop(move |state, mechanisms| replay_loop(state, mechanisms, entries))
+        .add_mechanism(Observer(log.clone()))
+        .build()
+        .unwrap()
+        .set_the_clock();
+    let log = log.lock().clone();
+    log
+}
+
+fn timing(ticks_total: u64) -> SessionEntry<StandardEvent, InputChange> {
+    SessionEntry::Timing(SessionTiming {
+        tick_delta: Duration::from_millis(16),
+        ticks_total,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn input_and_timings_are_replayed_between_events() {
+    use StandardEvent::*;
+    let log = replay(vec![
+        SessionEntry::Event(Initialization),
+        timing(1),
+        SessionEntry::Event(Tick),
+        SessionEntry::Input(InputChange::Pressed(VirtualKeyCode::W)),
+        timing(2),
+        SessionEntry::Event(Tick),
+        SessionEntry::Input(InputChange::Released(VirtualKeyCode::W)),
+        SessionEntry::Event(Termination),
+    ]);
+    assert_eq!(
+        log,
+        vec![
+            (Initialization, 0, false),
+            (Tick, 1, false),
+            (Tick, 2, true),
+            (Termination, 2, false),
+        ]
+    );
+}
+
+#[test]
+fn entries_after_the_termination_are_dropped() {
+    use StandardEvent::*;
+    let log = replay(vec![
+        SessionEntry::Event(Tick),
+        SessionEntry::Event(Termination),
+        timing(5),
+        SessionEntry::Event(Tick),
+    ]);
+    assert_eq!(log, vec![(Tick, 0, false), (Termination, 0, false)]);
+}
+
+#[test]
+fn termination_is_clinked_after_the_last_entry() {
+    use StandardEvent::*;
+    let log = replay(vec![SessionEntry::Event(Tick), timing(1)]);
+    assert_eq!(log, vec![(Tick, 0, false), (Termination, 1, false)]);
+}
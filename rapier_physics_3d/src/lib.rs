@@ -1,8 +1,10 @@
 pub mod mechanism;
+pub mod plugin;
 pub mod state;
 
 pub mod prelude {
     pub use crate::mechanism::*;
+    pub use crate::plugin::*;
     pub use crate::state::*;
     pub use rapier3d::prelude::*;
 }
@@ -87,16 +87,4 @@ where
     fn handled_events(&self) -> Option<Vec<StandardEvent>> {
         Some(vec![StandardEvent::Tick])
     }
-
-    fn initialization(&mut self, _: &mut EngineState<S>) {
-        unreachable!()
-    }
-
-    fn draw(&mut self, _: &mut EngineState<S>) {
-        unreachable!()
-    }
-
-    fn termination(&mut self, _: &mut EngineState<S>) {
-        unreachable!()
-    }
 }
@@ -0,0 +1,48 @@
+use crate::{mechanism::Rapier3DTicker, state::PhysicsState};
+use kernel::{
+    abstract_runtime::{MechanismOrdering, Substate},
+    clockwork::{ClockworkBuilder, ClockworkBuilderError},
+    plugin::Plugin,
+    standard_runtime::{StandardEventSuperset, StandardRuntimeStatistics},
+};
+use std::marker::PhantomData;
+
+/// A plugin, which steps the physics simulation of `PhysicsState` on every tick,
+/// using the tick delta of the statistics `T`.
+///
+/// The ticker is labeled as "physics", and is ordered after "ecs".
+pub struct Rapier3DPlugin<T>(PhantomData<T>)
+where
+    T: StandardRuntimeStatistics;
+
+impl<T> Default for Rapier3DPlugin<T>
+where
+    T: StandardRuntimeStatistics,
+{
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<S, E, T> Plugin<S, E> for Rapier3DPlugin<T>
+where
+    S: Substate<PhysicsState> + Substate<T>,
+    E: StandardEventSuperset,
+    T: StandardRuntimeStatistics,
+{
+    fn install(
+        self,
+        builder: ClockworkBuilder<S, E>,
+    ) -> Result<ClockworkBuilder<S, E>, ClockworkBuilderError> {
+        let ticker = Rapier3DTicker::<T>::builder().build().map_err(|error| {
+            ClockworkBuilderError::ValidationError(format!(
+                "Failed to build the physics ticker: {}",
+                error
+            ))
+        })?;
+        Ok(builder.add_ordered_standard_mechanism(
+            ticker,
+            MechanismOrdering::labeled("physics").after("ecs"),
+        ))
+    }
+}
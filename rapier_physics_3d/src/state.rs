@@ -1,5 +1,5 @@
 use kernel::{
-    abstract_runtime::ClockworkState,
+    abstract_runtime::{ClockworkState, Snapshot},
     prelude::{Deserialize, Serialize},
     util::derive_builder::Builder,
 };
@@ -66,6 +66,10 @@ impl PhysicsState {
 
 impl ClockworkState for PhysicsState {}
 
+impl Snapshot for PhysicsState {
+    const KEY: &'static str = "physics";
+}
+
 impl<T> From<T> for Gravity
 where
     T: Into<Vector<f32>>,
@@ -1,11 +1,26 @@
 use kernel::{graphics::scene_object_components::ProjectionMatrix, math::Mat4};
-use physics::prelude::nalgebra::{Matrix4, Orthographic3, Perspective3};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Camera {
-    Perspective(Perspective3<f32>),
-    Orthographic(Orthographic3<f32>),
+    /// A perspective projection, given the aspect ratio (width / height),
+    /// and the vertical field of view in radians
+    Perspective {
+        aspect: f32,
+        fovy: f32,
+        znear: f32,
+        zfar: f32,
+    },
+
+    /// An orthographic projection of the box
+    Orthographic {
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        znear: f32,
+        zfar: f32,
+    },
 }
 
 impl ProjectionMatrix for Camera {
@@ -14,23 +29,30 @@ impl ProjectionMatrix for Camera {
     }
 }
 
-impl Into<Matrix4<f32>> for Camera {
-    fn into(self) -> Matrix4<f32> {
-        match self {
-            Camera::Perspective(x) => x.into(),
-            Camera::Orthographic(x) => x.into(),
-        }
-    }
-}
-
+/// The projection matrix is converted to the column-major array, as expected by the shaders
 impl Into<[[f32; 4]; 4]> for Camera {
     fn into(self) -> [[f32; 4]; 4] {
-        Into::<Matrix4<f32>>::into(self).into()
+        Into::<Mat4>::into(self).transpose().into()
     }
 }
 
 impl Into<Mat4> for Camera {
     fn into(self) -> Mat4 {
-        Into::<[[f32; 4]; 4]>::into(self).into()
+        match self {
+            Camera::Perspective {
+                aspect,
+                fovy,
+                znear,
+                zfar,
+            } => Mat4::perspective(aspect, fovy, znear, zfar),
+            Camera::Orthographic {
+                left,
+                right,
+                bottom,
+                top,
+                znear,
+                zfar,
+            } => Mat4::orthographic(left, right, bottom, top, znear, zfar),
+        }
     }
 }
@@ -1,4 +1,5 @@
 pub mod mechanism;
+pub mod plugin;
 pub mod state;
 pub mod vulkano_layer;
 
@@ -9,6 +10,7 @@ pub use vulkano_win;
 pub mod prelude {
     pub use crate::gui::Gui;
     pub use crate::mechanism::VulkanoGraphics;
+    pub use crate::plugin::VulkanoGraphicsPlugin;
     pub use crate::state::GraphicsState;
 }
 
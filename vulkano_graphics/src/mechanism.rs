@@ -1,13 +1,13 @@
 use crate::{
     state::{
-        init_vulkano, window_size_dependent_setup, GraphicsState, GuiState, InternalMechanismState,
-        StateRequirements,
+        init_vulkano, init_vulkano_on_surface, window_size_dependent_setup, GraphicsState,
+        GuiState, InternalMechanismState, StateRequirements,
     },
     vulkano_layer::VulkanoLayer,
 };
 use kernel::{
     abstract_runtime::{ClockworkState, EngineState},
-    util::{derive_builder::Builder, init_state::InitState, sync::WriteLock},
+    util::{derive_builder::Builder, init_state::InitState, log::warn, sync::WriteLock},
 };
 use kernel::{
     prelude::StandardEvent,
@@ -53,6 +53,34 @@ where
     pub fn builder() -> VulkanoGraphicsBuilder<S, E> {
         Default::default()
     }
+
+    /// Rebuilds the device, the swapchain, the GUI, and the layer states
+    /// on the same window surface after the device loss.
+    fn rebuild(&mut self, state: &mut EngineState<S>) {
+        warn!("Vulkan device has been lost, rebuilding the graphics");
+        let Self { layers, inner, .. } = self;
+        let mut surface = None;
+        inner.restart(|lost| {
+            let InternalMechanismState {
+                swapchain,
+                graphics_state,
+                ..
+            } = lost.expect("Only the initialized graphics can lose the device");
+            layers
+                .iter_mut()
+                .for_each(|layer| layer.termination(state, &graphics_state));
+            surface = Some(swapchain.surface().clone());
+        });
+        let (internal, gui) = init_vulkano_on_surface(surface.unwrap());
+        inner.initialize(|()| internal);
+        state
+            .start_mutate()
+            .get_mut(|s: &mut GuiState| s.replace_gui(gui))
+            .finish();
+        layers
+            .iter_mut()
+            .for_each(|layer| layer.initialization(state, &inner.get_init().graphics_state))
+    }
 }
 
 impl<S, E> VulkanoGraphicsBuilder<S, E>
@@ -117,19 +145,16 @@ where
 
         /* ---- DRAWING ---- */
         draw(state, &mut self.layers, self.inner.get_init_mut());
+
+        /* ---- HANDLING DEVICE LOSS ---- */
+        if self.inner.get_init().device_lost {
+            self.rebuild(state)
+        }
     }
 
     fn handled_events(&self) -> Option<Vec<StandardEvent>> {
         Some(vec![StandardEvent::Initialization, StandardEvent::Draw])
     }
-
-    fn tick(&mut self, _: &mut EngineState<S>) {
-        unreachable!()
-    }
-
-    fn termination(&mut self, _: &mut EngineState<S>) {
-        unreachable!()
-    }
 }
 
 fn draw<S, E>(
@@ -139,6 +164,7 @@ fn draw<S, E>(
         swapchain,
         previous_frame_end,
         recreate_swapchain,
+        device_lost,
         framebuffers,
         graphics_state,
     }: &mut InternalMechanismState,
@@ -184,6 +210,10 @@ fn draw<S, E>(
                 *recreate_swapchain = true;
                 return;
             }
+            Err(AcquireError::DeviceLost) => {
+                *device_lost = true;
+                return;
+            }
             Err(e) => panic!("Failed to acquire next image: {:?}", e),
         };
 
@@ -255,6 +285,10 @@ fn draw<S, E>(
             *recreate_swapchain = true;
             Some(sync::now(device.clone()).boxed())
         }
+        Err(FlushError::DeviceLost) => {
+            *device_lost = true;
+            Some(sync::now(device.clone()).boxed())
+        }
         Err(e) => panic!("{}", e),
     };
 }
@@ -0,0 +1,85 @@
+use crate::{
+    mechanism::{VulkanoGraphics, VulkanoGraphicsBuilder},
+    state::StateRequirements,
+    vulkano_layer::VulkanoLayer,
+};
+use kernel::{
+    abstract_runtime::MechanismOrdering,
+    clockwork::{ClockworkBuilder, ClockworkBuilderError},
+    plugin::Plugin,
+    standard_runtime::StandardEventSuperset,
+};
+
+/// A plugin, which renders the layers into the window of the main loop.
+///
+/// The graphics mechanism is labeled as "graphics",
+/// and is ordered after "ecs", and "physics".
+pub struct VulkanoGraphicsPlugin<S, E>
+where
+    S: StateRequirements<E>,
+    E: StandardEventSuperset,
+{
+    /// The builder of the graphics mechanism
+    graphics: VulkanoGraphicsBuilder<S, E>,
+
+    /// The amount of added layers
+    layers: usize,
+}
+
+impl<S, E> Default for VulkanoGraphicsPlugin<S, E>
+where
+    S: StateRequirements<E>,
+    E: StandardEventSuperset,
+{
+    fn default() -> Self {
+        Self {
+            graphics: VulkanoGraphics::builder(),
+            layers: 0,
+        }
+    }
+}
+
+impl<S, E> VulkanoGraphicsPlugin<S, E>
+where
+    S: StateRequirements<E>,
+    E: StandardEventSuperset,
+{
+    /// Adds the layer, which is drawn after the previously added ones.
+    pub fn add_layer(self, layer: impl VulkanoLayer<S> + 'static) -> Self {
+        Self {
+            graphics: self.graphics.add_layer(layer),
+            layers: self.layers + 1,
+        }
+    }
+}
+
+impl<S, E> Plugin<S, E> for VulkanoGraphicsPlugin<S, E>
+where
+    S: StateRequirements<E>,
+    E: StandardEventSuperset,
+{
+    fn validate(&self) -> Result<(), String> {
+        match self.layers {
+            0 => Err("No layers have been added".into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn install(
+        self,
+        builder: ClockworkBuilder<S, E>,
+    ) -> Result<ClockworkBuilder<S, E>, ClockworkBuilderError> {
+        let graphics = self.graphics.build().map_err(|error| {
+            ClockworkBuilderError::ValidationError(format!(
+                "Failed to build the graphics mechanism: {}",
+                error
+            ))
+        })?;
+        Ok(builder.add_ordered_standard_mechanism(
+            graphics,
+            MechanismOrdering::labeled("graphics")
+                .after("ecs")
+                .after("physics"),
+        ))
+    }
+}
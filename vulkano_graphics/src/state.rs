@@ -17,7 +17,7 @@ use vulkano::{
     image::{view::ImageView, AttachmentImage, ImageUsage, SwapchainImage},
     instance::Instance,
     render_pass::{Framebuffer, FramebufferAbstract, RenderPass, Subpass},
-    swapchain::Swapchain,
+    swapchain::{Surface, Swapchain},
     sync::{self, GpuFuture},
     Version,
 };
@@ -36,6 +36,7 @@ pub(crate) struct InternalMechanismState {
     pub swapchain: Arc<Swapchain<Window>>,
     pub previous_frame_end: Option<Box<dyn GpuFuture>>,
     pub recreate_swapchain: bool,
+    pub device_lost: bool,
     pub framebuffers: Vec<Arc<dyn FramebufferAbstract + Send + Sync>>,
     pub graphics_state: GraphicsState,
 }
@@ -85,6 +86,14 @@ impl GuiState {
         self.inner.initialize(|draw_fn| (draw_fn, gui))
     }
 
+    /// Replaces the GUI, which has been rebuilt after the device loss.
+    ///
+    /// The lock is kept, so the window event callback keeps updating the GUI.
+    pub(crate) fn replace_gui(&mut self, gui: Gui) {
+        let (_, old_gui) = self.inner.get_init_mut();
+        *old_gui.lock_mut() = gui
+    }
+
     /// Draw call
     pub(crate) fn init_draw_on_subpass_image(
         &mut self,
@@ -142,6 +151,18 @@ where
         })
         .finish();
 
+    init_vulkano_on_surface(surface)
+}
+
+/// Initializes the device, the swapchain, and the GUI on the existing window surface.
+///
+/// Unlike `init_vulkano`, it does not need the uninitialized event loop,
+/// so it is also used to rebuild the graphics after the device loss.
+pub(crate) fn init_vulkano_on_surface(
+    surface: Arc<Surface<Window>>,
+) -> (InternalMechanismState, Gui) {
+    let instance = surface.instance().clone();
+
     trace!("Getting physical device");
     debug!(
         "Available devices: {:?}",
@@ -257,6 +278,7 @@ where
             swapchain: swapchain.clone(),
             previous_frame_end: Some(sync::now(device.clone()).boxed()),
             recreate_swapchain: false,
+            device_lost: false,
             framebuffers,
             graphics_state: GraphicsState {
                 target_image_size: {
@@ -17,5 +17,8 @@ where
         graphics_state: &GraphicsState,
     ) -> SecondaryAutoCommandBuffer;
 
+    /// Disposes the layer state, which depends on the graphics device.
+    ///
+    /// The layer is initialized again, if the graphics are rebuilt after the device loss.
     fn termination(&mut self, engine_state: &EngineState<S>, graphics_state: &GraphicsState);
 }
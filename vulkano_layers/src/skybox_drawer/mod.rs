@@ -290,7 +290,9 @@ where
             .unwrap()
     }
 
-    fn termination(&mut self, _: &EngineState<S>, _: &GraphicsState) {}
+    fn termination(&mut self, _: &EngineState<S>, _: &GraphicsState) {
+        self.0.restart(|_| ())
+    }
 }
 
 mod buffered_mesh;
@@ -24,7 +24,9 @@ pub struct InnerState<I>
 where
     I: AssetStorageKey,
 {
-    pub buffered_meshes: HashMap<I, BufferedMesh>,
+    /// Buffered meshes, along with the generations of the mesh and the material locks,
+    /// from which they have been buffered
+    pub buffered_meshes: HashMap<I, (BufferedMesh, (u64, u64))>,
     pub pipeline: Arc<GraphicsPipeline>,
     pub vertex_uniform_pool: CpuBufferPool<vertex_shader::ty::Data>,
     pub fragment_uniform_mesh_pool: CpuBufferPool<fragment_shader::ty::DataMesh>,
@@ -131,13 +133,11 @@ pub fn generate_pipeline(
     )
 }
 
-pub fn make_vertex_uniforms(
-    projection: impl AsRef<[[f32; 4]; 4]>,
-    view: impl AsRef<[[f32; 4]; 4]>,
-) -> vertex_shader::ty::Data {
+/// Makes the vertex uniforms, transposing the matrices into the column-major layout of the shader
+pub fn make_vertex_uniforms(projection: Mat4, view: Mat4) -> vertex_shader::ty::Data {
     vertex_shader::ty::Data {
-        projection: projection.as_ref().clone(),
-        view: view.as_ref().clone(),
+        projection: projection.transpose().into(),
+        view: view.transpose().into(),
     }
 }
 
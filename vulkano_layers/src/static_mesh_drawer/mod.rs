@@ -1,4 +1,7 @@
-use self::inner_state::{generate_pipeline, InnerState};
+use self::{
+    buffered_mesh::BufferedMesh,
+    inner_state::{generate_pipeline, InnerState},
+};
 use asset_storage::asset_storage::AssetStorageKey;
 use graphics::{state::GraphicsState, vulkano_layer::VulkanoLayer};
 use kernel::{
@@ -201,23 +204,35 @@ where
                     (
                         mesh_id.clone(),
                         instances,
-                        buffered_meshes
-                            .entry(mesh_id.clone())
-                            .or_insert_with(|| {
-                                engine_state
-                                    .start_access()
-                                    .get(|materials: &PhongMaterialStorage<_>| {
-                                        materials.get(mesh_id.clone()).clone()
-                                    })
-                                    .then_get_zip(|meshes: &TexturedMeshStorage<_>| {
-                                        meshes.get(mesh_id.clone()).clone()
-                                    })
-                                    .map(|(material, mesh)| {
-                                        (graphics_state, &*mesh.lock(), &*material.lock()).into()
-                                    })
-                                    .finish()
+                        engine_state
+                            .start_access()
+                            .get(|materials: &PhongMaterialStorage<_>| {
+                                materials.get(mesh_id.clone())
                             })
-                            .clone(),
+                            .then_get_zip(|meshes: &TexturedMeshStorage<_>| {
+                                meshes.get(mesh_id.clone())
+                            })
+                            .map(|(material, mesh)| {
+                                let generations = (mesh.generation(), material.generation());
+                                match buffered_meshes.get(&mesh_id) {
+                                    Some((buffered_mesh, buffered_generations))
+                                        if buffered_generations == &generations =>
+                                    {
+                                        buffered_mesh.clone()
+                                    }
+                                    _ => {
+                                        let buffered_mesh: BufferedMesh =
+                                            (graphics_state, &*mesh.lock(), &*material.lock())
+                                                .into();
+                                        buffered_meshes.insert(
+                                            mesh_id.clone(),
+                                            (buffered_mesh.clone(), generations),
+                                        );
+                                        buffered_mesh
+                                    }
+                                }
+                            })
+                            .finish(),
                     )
                 });
 
@@ -354,9 +369,9 @@ where
                                     graphics_state.device.clone(),
                                     BufferUsage::all(),
                                     false,
-                                    instances
-                                        .into_iter()
-                                        .map(|entity| entity.world_matrix().as_ref().clone()),
+                                    instances.into_iter().map(|entity| -> [[f32; 4]; 4] {
+                                        entity.world_matrix().transpose().into()
+                                    }),
                                 )
                                 .unwrap(),
                             ),
@@ -379,7 +394,9 @@ where
         self.inner.get_init_mut().pipeline = generate_pipeline(graphics_state)
     }
 
-    fn termination(&mut self, _: &EngineState<StateT>, _: &GraphicsState) {}
+    fn termination(&mut self, _: &EngineState<StateT>, _: &GraphicsState) {
+        self.inner.restart(|_| ())
+    }
 }
 
 mod buffered_mesh;